@@ -0,0 +1,182 @@
+# German interface texts: key = text.
+# Missing keys fall back to English.
+
+## Language
+language-name = Deutsch
+# Usual decimal separator: . or ,
+language-decimal = ,
+
+## Interface
+app-title = Flächenrechner
+input-units = Eingabeeinheiten
+output-units = Ausgabeeinheiten
+factor = Faktor
+calculate = Berechnen
+clear = Leeren
+copy = In Zwischenablage kopieren
+copy-list = Liste mit Bezeichnungen und Notizen kopieren
+total = Summe:
+edit = Form bearbeiten
+cancel = Abbrechen
+save = Speichern
+
+## Units
+mm = mm
+sm = cm
+dm = dm
+m = m
+mm2 = mm²
+sm2 = cm²
+dm2 = dm²
+m2 = m²
+
+## Materials
+material = Werkstoff
+mass = Masse:
+kg = kg
+per-item = pro Stück
+steel = Stahl
+aluminium = Aluminium
+brass = Messing
+copper = Kupfer
+
+## Electroplating
+plating = Galvanik
+metal = Metall
+current-density = Stromdichte, A/dm²
+thickness = Schichtdicke, µm
+efficiency = Stromausbeute, %
+current = Strom:
+time = Zeit:
+metal-consumption = Metallverbrauch:
+ampere = A
+minute = min
+gram = g
+zinc = Zink
+nickel = Nickel
+chromium = Chrom
+tin = Zinn
+silver = Silber
+
+## Coating consumption
+coating = Beschichtungsverbrauch
+coating-type = Beschichtung
+paint = Nasslack
+powder = Pulverlack
+coverage = Ergiebigkeit, m²/Einheit
+film-thickness = Trockenschichtdicke, µm
+volume-solids = Festkörpervolumen, %
+specific-gravity = Dichte, g/cm³
+transfer-efficiency = Auftragswirkungsgrad, %
+consumption = Verbrauch:
+litre = l
+
+## Pricing
+pricing = Preise
+price = Preis pro
+min-charge = Mindestpreis pro Position
+setup-fee = Rüstkosten
+quote-total = Angebotssumme:
+
+## Results list
+group = Gruppe
+default-group = Haupt
+rename-group = Gruppe umbenennen
+delete-group = Gruppe löschen, ihre Ergebnisse kommen in die erste Gruppe
+label = Bezeichnung
+note = Notiz
+undo = Rückgängig (Strg+Z)
+redo = Wiederholen (Strg+Umschalt+Z)
+duplicate = Duplizieren
+drag-to-move = Zum Verschieben ziehen
+selected = Ausgewählt
+set-factor = Faktor setzen
+delete-selected = Ausgewählte löschen
+multiply-factor = Faktor multiplizieren
+multiply-factor-hint = Ausgewählte Ergebnisse oder alle, wenn nichts ausgewählt ist
+area-range = Fläche min…max:
+
+## Number format
+number-format = Zahlenformat
+decimals = Nachkommastellen
+significant = Signifikante Stellen
+rounding = Rundung
+half-up = Kaufmännisch
+half-even = Mathematisch (gerade)
+round-down = Abrunden (abschneiden)
+round-up = Aufrunden
+grouping = Tausendertrennzeichen
+decimal-separator = Dezimaltrennzeichen
+no-grouping = Keines
+
+## Working
+working = Formel und Rechenweg
+area = Fläche
+
+## Messages
+buffer-error = Systemzwischenablage nicht verfügbar
+buffer-copied = In die Zwischenablage kopiert
+buffer-fail = Kopieren in die Zwischenablage fehlgeschlagen
+calculation-err = Berechnungsfehler
+input-wrong = Ungültige Eingabe
+parse-wrong = Fehler bei der Umwandlung in eine Zahl
+wrong-field = Fehler beim Zugriff auf das Eingabefeld
+shape-fail = Unbekannte Form
+wrong-bushing = Der Innendurchmesser muss kleiner als der Außendurchmesser sein
+wrong-dimensions = Falsche Anzahl von Maßen
+unknown-option = Unbekannte Option
+ambiguous-option = Die Option passt zu mehreren Auswahlen, geben Sie mehr davon ein
+unknown-unit = Unbekannte Einheit
+wrong-pitch = Die Gewindesteigung ist zu groß für den Durchmesser
+missing-length = Geben Sie die Länge der Schraube ein
+ambiguous-number = Die Zahl ist mehrdeutig: Tausender- oder Dezimaltrennzeichen, geben Sie den Nachkommateil an
+
+## Shapes
+circle = Kreis
+diameter-cir = Durchmesser
+rectangle = Rechteck
+lenght-rec = Länge
+height-rec = Höhe
+height-cyl = Höhe
+diameter-cyl = Durchmesser
+cylinder = Zylinder
+threaded = Gewinde
+diameter-hex = Durchmesser
+circumscribed = Umkreis
+hexagon = Sechseck
+hex-prism = Sechskantprisma
+hex-prism-height = Höhe
+bushing = Buchse
+bushing-height = Höhe
+bushing-diameter = Außendurchmesser
+bushing-inner-diameter = Innendurchmesser
+cuboid = Quader
+cuboid-height = Höhe
+cuboid-breadth = Tiefe
+cuboid-width = Breite
+base-area = Grundfläche
+lateral-area = Mantelfläche
+end-face = Stirnfläche
+outer-surface = Außenfläche
+inner-surface = Innenfläche
+faces-ab = Flächen a×b
+faces-ac = Flächen a×c
+faces-bc = Flächen b×c
+thread-multiplier = Gewindefaktor
+thread-pitch = Gewindesteigung
+thread-profile = Gewindeprofil
+metric = Metrisch
+trapezoidal = Trapezgewinde
+fastener = Normteil
+fastener-kind = Verbindungselement
+fastener-size = Größe
+bolt-length = Schraubenlänge
+bolt = Sechskantschraube
+nut = Sechskantmutter
+washer = Unterlegscheibe
+bolt-head = Kopf
+bolt-shank = Schaft
+bolt-thread = Gewinde
+nut-faces = Stirnflächen und Schlüsselflächen
+washer-faces = Flächen
+washer-edges = Kanten
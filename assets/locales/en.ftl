@@ -0,0 +1,182 @@
+# English interface texts: key = text.
+# Every key used by the program must be present here.
+
+## Language
+language-name = English
+# Usual decimal separator: . or ,
+language-decimal = .
+
+## Interface
+app-title = Area calculator
+input-units = Input units
+output-units = Output units
+factor = Factor
+calculate = Calculate
+clear = Clear
+copy = Copy to buffer
+copy-list = Copy the list with labels and notes
+total = Total:
+edit = Edit shape
+cancel = Cancel
+save = Save
+
+## Units
+mm = mm
+sm = sm
+dm = dm
+m = m
+mm2 = mm²
+sm2 = sm²
+dm2 = dm²
+m2 = m²
+
+## Materials
+material = Material
+mass = Mass:
+kg = kg
+per-item = per item
+steel = Steel
+aluminium = Aluminium
+brass = Brass
+copper = Copper
+
+## Electroplating
+plating = Electroplating
+metal = Metal
+current-density = Current density, A/dm²
+thickness = Coating thickness, µm
+efficiency = Current efficiency, %
+current = Current:
+time = Time:
+metal-consumption = Metal consumption:
+ampere = A
+minute = min
+gram = g
+zinc = Zinc
+nickel = Nickel
+chromium = Chromium
+tin = Tin
+silver = Silver
+
+## Coating consumption
+coating = Coating consumption
+coating-type = Coating
+paint = Paint
+powder = Powder
+coverage = Coverage rate, m²/unit
+film-thickness = Dry film thickness, µm
+volume-solids = Volume solids, %
+specific-gravity = Specific gravity, g/cm³
+transfer-efficiency = Transfer efficiency, %
+consumption = Consumption:
+litre = L
+
+## Pricing
+pricing = Pricing
+price = Price per
+min-charge = Minimum charge per item
+setup-fee = Setup fee
+quote-total = Quote total:
+
+## Results list
+group = Group
+default-group = Main
+rename-group = Rename the group
+delete-group = Delete the group, its results move to the first group
+label = Label
+note = Note
+undo = Undo (Ctrl+Z)
+redo = Redo (Ctrl+Shift+Z)
+duplicate = Duplicate
+drag-to-move = Drag to move
+selected = Selected
+set-factor = Set factor
+delete-selected = Delete selected
+multiply-factor = Multiply factor
+multiply-factor-hint = Selected results, or all when nothing is selected
+area-range = Area min…max:
+
+## Number format
+number-format = Number format
+decimals = Decimal places
+significant = Significant digits
+rounding = Rounding
+half-up = Half up
+half-even = Half even
+round-down = Down (truncate)
+round-up = Up
+grouping = Thousands separator
+decimal-separator = Decimal separator
+no-grouping = None
+
+## Working
+working = Formula and working
+area = Area
+
+## Messages
+buffer-error = System clipboard unavailable
+buffer-copied = Copied to clipboard
+buffer-fail = Copy to clipboard failed
+calculation-err = Calculation error
+input-wrong = Incorrect input
+parse-wrong = Error converting to a digit
+wrong-field = Error accessing to the input firld
+shape-fail = Unknown shape
+wrong-bushing = The inner diameter must be smaller than the outer
+wrong-dimensions = Wrong number of dimensions
+unknown-option = Unknown option
+ambiguous-option = The option matches several choices, type more of it
+unknown-unit = Unknown unit
+wrong-pitch = The thread pitch is too large for the diameter
+missing-length = Enter the length of the bolt
+ambiguous-number = The number could be grouped or decimal, add the decimal part
+
+## Shapes
+circle = Circle
+diameter-cir = Diameter
+rectangle = Rectangle
+lenght-rec = Lenght
+height-rec = Height
+height-cyl = Height
+diameter-cyl = Diameter
+cylinder = Cylinder
+threaded = Threaded
+diameter-hex = Diameter
+circumscribed = Circumscribed circle
+hexagon = Hexagon
+hex-prism = Hexagon prism
+hex-prism-height = Height
+bushing = Bushing
+bushing-height = Height
+bushing-diameter = Outer diameter
+bushing-inner-diameter = Inner diameter
+cuboid = Cuboid
+cuboid-height = Height
+cuboid-breadth = Breadth
+cuboid-width = Width
+base-area = Base area
+lateral-area = Lateral area
+end-face = End face
+outer-surface = Outer surface
+inner-surface = Inner surface
+faces-ab = Faces a×b
+faces-ac = Faces a×c
+faces-bc = Faces b×c
+thread-multiplier = Thread multiplier
+thread-pitch = Thread pitch
+thread-profile = Thread profile
+metric = Metric
+trapezoidal = Trapezoidal
+fastener = Standard fastener
+fastener-kind = Fastener
+fastener-size = Size
+bolt-length = Bolt length
+bolt = Hex bolt
+nut = Hex nut
+washer = Washer
+bolt-head = Head
+bolt-shank = Shank
+bolt-thread = Thread
+nut-faces = Faces and flats
+washer-faces = Faces
+washer-edges = Edges
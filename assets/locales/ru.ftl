@@ -0,0 +1,182 @@
+# Russian interface texts: key = text.
+# Missing keys fall back to English.
+
+## Language
+language-name = Русский
+# Usual decimal separator: . or ,
+language-decimal = ,
+
+## Interface
+app-title = Калькулятор площади
+input-units = Единицы ввода
+output-units = Единицы вывода
+factor = Коэффицент
+calculate = Рассчитать
+clear = Очистить
+copy = Скопировать
+copy-list = Скопировать список с метками и заметками
+total = Итого:
+edit = Редактировать
+cancel = Отмена
+save = Сохранить
+
+## Units
+mm = мм
+sm = см
+dm = дм
+m = м
+mm2 = мм²
+sm2 = см²
+dm2 = дм²
+m2 = м²
+
+## Materials
+material = Материал
+mass = Масса:
+kg = кг
+per-item = за шт.
+steel = Сталь
+aluminium = Алюминий
+brass = Латунь
+copper = Медь
+
+## Electroplating
+plating = Гальваника
+metal = Металл
+current-density = Плотность тока, А/дм²
+thickness = Толщина покрытия, мкм
+efficiency = Выход по току, %
+current = Ток:
+time = Время:
+metal-consumption = Расход металла:
+ampere = А
+minute = мин
+gram = г
+zinc = Цинк
+nickel = Никель
+chromium = Хром
+tin = Олово
+silver = Серебро
+
+## Coating consumption
+coating = Расход покрытия
+coating-type = Покрытие
+paint = Краска
+powder = Порошковая краска
+coverage = Укрывистость, м²/ед.
+film-thickness = Толщина сухой плёнки, мкм
+volume-solids = Сухой остаток, % об.
+specific-gravity = Плотность, г/см³
+transfer-efficiency = Коэффициент переноса, %
+consumption = Расход:
+litre = л
+
+## Pricing
+pricing = Стоимость
+price = Цена за
+min-charge = Минимальная цена позиции
+setup-fee = Стоимость наладки
+quote-total = Итого по заказу:
+
+## Results list
+group = Группа
+default-group = Основная
+rename-group = Переименовать группу
+delete-group = Удалить группу, её результаты перейдут в первую группу
+label = Метка
+note = Примечание
+undo = Отменить (Ctrl+Z)
+redo = Повторить (Ctrl+Shift+Z)
+duplicate = Дублировать
+drag-to-move = Перетащите для перемещения
+selected = Выбрано
+set-factor = Задать множитель
+delete-selected = Удалить выбранные
+multiply-factor = Умножить множитель
+multiply-factor-hint = Для выбранных результатов или для всех, если ничего не выбрано
+area-range = Площадь мин…макс:
+
+## Number format
+number-format = Формат чисел
+decimals = Знаков после запятой
+significant = Значащих цифр
+rounding = Округление
+half-up = Математическое
+half-even = Банковское
+round-down = Вниз (отбросить)
+round-up = Вверх
+grouping = Разряды
+decimal-separator = Десятичный разделитель
+no-grouping = Нет
+
+## Working
+working = Формула и расчёт
+area = Площадь
+
+## Messages
+buffer-error = Буфер обмена не доступен
+buffer-copied = Скопированно в буфер обмена
+buffer-fail = Не удалось скопировать в буфер обмена
+calculation-err = Ошибка вычисления
+input-wrong = Некорректный ввод
+parse-wrong = Ошибка преобразования в число
+wrong-field = Ошибка доступа у полю ввода
+shape-fail = Неизвестная фигура
+wrong-bushing = Внутренний диаметр должен быть меньше внешнего
+wrong-dimensions = Неверное количество размеров
+unknown-option = Неизвестная опция
+ambiguous-option = Опция подходит к нескольким вариантам, введите её длиннее
+unknown-unit = Неизвестная единица измерения
+wrong-pitch = Шаг резьбы слишком велик для диаметра
+missing-length = Введите длину болта
+ambiguous-number = Число неоднозначно: разделитель разрядов или дробной части, добавьте дробную часть
+
+## Shapes
+circle = Круг
+diameter-cir = Диаметр
+rectangle = Прямоугольник
+lenght-rec = Длина
+height-rec = Высота
+height-cyl = Высота
+diameter-cyl = Диаметр
+cylinder = Цилиндр
+threaded = Резьба
+diameter-hex = Диаметр
+circumscribed = Описанная окружность
+hexagon = Шестиугольник
+hex-prism = Шестиугольная призма
+hex-prism-height = Высота
+bushing = Втулка
+bushing-height = Высота
+bushing-diameter = Диаметр втулки
+bushing-inner-diameter = Диаметр отверстия
+cuboid = Параллелепипед
+cuboid-height = Высота
+cuboid-breadth = Глубина
+cuboid-width = Ширина
+base-area = Площадь основания
+lateral-area = Боковая поверхность
+end-face = Торец
+outer-surface = Наружная поверхность
+inner-surface = Внутренняя поверхность
+faces-ab = Грани a×b
+faces-ac = Грани a×c
+faces-bc = Грани b×c
+thread-multiplier = Коэффициент резьбы
+thread-pitch = Шаг резьбы
+thread-profile = Профиль резьбы
+metric = Метрическая
+trapezoidal = Трапецеидальная
+fastener = Стандартный крепёж
+fastener-kind = Крепёж
+fastener-size = Размер
+bolt-length = Длина болта
+bolt = Болт с шестигранной головкой
+nut = Шестигранная гайка
+washer = Шайба
+bolt-head = Головка
+bolt-shank = Стержень
+bolt-thread = Резьба
+nut-faces = Торцы и грани
+washer-faces = Плоскости
+washer-edges = Кромки
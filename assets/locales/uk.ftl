@@ -0,0 +1,182 @@
+# Ukrainian interface texts: key = text.
+# Missing keys fall back to English.
+
+## Language
+language-name = Українська
+# Usual decimal separator: . or ,
+language-decimal = ,
+
+## Interface
+app-title = Калькулятор площі
+input-units = Одиниці вводу
+output-units = Одиниці виводу
+factor = Коефіцієнт
+calculate = Розрахувати
+clear = Очистити
+copy = Скопіювати
+copy-list = Скопіювати список з мітками та нотатками
+total = Разом:
+edit = Редагувати
+cancel = Скасувати
+save = Зберегти
+
+## Units
+mm = мм
+sm = см
+dm = дм
+m = м
+mm2 = мм²
+sm2 = см²
+dm2 = дм²
+m2 = м²
+
+## Materials
+material = Матеріал
+mass = Маса:
+kg = кг
+per-item = за шт.
+steel = Сталь
+aluminium = Алюміній
+brass = Латунь
+copper = Мідь
+
+## Electroplating
+plating = Гальваніка
+metal = Метал
+current-density = Густина струму, А/дм²
+thickness = Товщина покриття, мкм
+efficiency = Вихід за струмом, %
+current = Струм:
+time = Час:
+metal-consumption = Витрата металу:
+ampere = А
+minute = хв
+gram = г
+zinc = Цинк
+nickel = Нікель
+chromium = Хром
+tin = Олово
+silver = Срібло
+
+## Coating consumption
+coating = Витрата покриття
+coating-type = Покриття
+paint = Фарба
+powder = Порошкова фарба
+coverage = Покривність, м²/од.
+film-thickness = Товщина сухої плівки, мкм
+volume-solids = Сухий залишок, % об.
+specific-gravity = Густина, г/см³
+transfer-efficiency = Коефіцієнт перенесення, %
+consumption = Витрата:
+litre = л
+
+## Pricing
+pricing = Вартість
+price = Ціна за
+min-charge = Мінімальна ціна позиції
+setup-fee = Вартість налагодження
+quote-total = Разом за замовленням:
+
+## Results list
+group = Група
+default-group = Основна
+rename-group = Перейменувати групу
+delete-group = Видалити групу, її результати перейдуть до першої групи
+label = Мітка
+note = Примітка
+undo = Скасувати (Ctrl+Z)
+redo = Повторити (Ctrl+Shift+Z)
+duplicate = Дублювати
+drag-to-move = Перетягніть для переміщення
+selected = Вибрано
+set-factor = Задати коефіцієнт
+delete-selected = Видалити вибрані
+multiply-factor = Помножити коефіцієнт
+multiply-factor-hint = Для вибраних результатів або для всіх, якщо нічого не вибрано
+area-range = Площа мін…макс:
+
+## Number format
+number-format = Формат чисел
+decimals = Знаків після коми
+significant = Значущих цифр
+rounding = Округлення
+half-up = Математичне
+half-even = Банківське
+round-down = Вниз (відкинути)
+round-up = Вгору
+grouping = Розряди
+decimal-separator = Десятковий роздільник
+no-grouping = Немає
+
+## Working
+working = Формула і розрахунок
+area = Площа
+
+## Messages
+buffer-error = Буфер обміну недоступний
+buffer-copied = Скопійовано в буфер обміну
+buffer-fail = Не вдалося скопіювати в буфер обміну
+calculation-err = Помилка обчислення
+input-wrong = Некоректне введення
+parse-wrong = Помилка перетворення на число
+wrong-field = Помилка доступу до поля введення
+shape-fail = Невідома фігура
+wrong-bushing = Внутрішній діаметр має бути меншим за зовнішній
+wrong-dimensions = Неправильна кількість розмірів
+unknown-option = Невідома опція
+ambiguous-option = Опція відповідає кільком варіантам, введіть її довше
+unknown-unit = Невідома одиниця виміру
+wrong-pitch = Крок різьби завеликий для діаметра
+missing-length = Введіть довжину болта
+ambiguous-number = Число неоднозначне: роздільник розрядів або дробової частини, додайте дробову частину
+
+## Shapes
+circle = Коло
+diameter-cir = Діаметр
+rectangle = Прямокутник
+lenght-rec = Довжина
+height-rec = Висота
+height-cyl = Висота
+diameter-cyl = Діаметр
+cylinder = Циліндр
+threaded = Різьба
+diameter-hex = Діаметр
+circumscribed = Описане коло
+hexagon = Шестикутник
+hex-prism = Шестикутна призма
+hex-prism-height = Висота
+bushing = Втулка
+bushing-height = Висота
+bushing-diameter = Діаметр втулки
+bushing-inner-diameter = Діаметр отвору
+cuboid = Паралелепіпед
+cuboid-height = Висота
+cuboid-breadth = Глибина
+cuboid-width = Ширина
+base-area = Площа основи
+lateral-area = Бічна поверхня
+end-face = Торець
+outer-surface = Зовнішня поверхня
+inner-surface = Внутрішня поверхня
+faces-ab = Грані a×b
+faces-ac = Грані a×c
+faces-bc = Грані b×c
+thread-multiplier = Коефіцієнт різьби
+thread-pitch = Крок різьби
+thread-profile = Профіль різьби
+metric = Метрична
+trapezoidal = Трапецеїдальна
+fastener = Стандартне кріплення
+fastener-kind = Кріплення
+fastener-size = Розмір
+bolt-length = Довжина болта
+bolt = Болт із шестигранною головкою
+nut = Шестигранна гайка
+washer = Шайба
+bolt-head = Головка
+bolt-shank = Стрижень
+bolt-thread = Різьба
+nut-faces = Торці та грані
+washer-faces = Площини
+washer-edges = Кромки
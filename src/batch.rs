@@ -0,0 +1,165 @@
+use std::io::{Read, Write};
+
+use area_calculator::literals::{self, messages};
+use area_calculator::{measure, number_format::NumberFormat, shapes};
+use serde::{Deserialize, Serialize};
+
+pub const FLAG: &str = "--batch";
+
+#[derive(Deserialize)]
+#[serde(untagged)]
+pub enum Value {
+    Number(f64),
+    Text(String),
+}
+
+impl Value {
+    fn text(&self) -> String {
+        match self {
+            Value::Number(number) => number.to_string(),
+            Value::Text(text) => text.clone(),
+        }
+    }
+}
+
+#[derive(Deserialize)]
+pub struct Part {
+    shape: String,
+    #[serde(default)]
+    dimensions: Vec<Value>,
+    factor: Option<Value>,
+    #[serde(default)]
+    options: Vec<String>,
+    input_units: Option<String>,
+    output_units: Option<String>,
+    #[serde(default)]
+    label: String,
+    #[serde(default)]
+    note: String,
+}
+
+#[derive(Serialize, Default)]
+pub struct PartResult {
+    #[serde(skip_serializing_if = "String::is_empty")]
+    label: String,
+    #[serde(skip_serializing_if = "String::is_empty")]
+    note: String,
+    area: Option<f64>,
+    result: Option<String>,
+    error: Option<&'static str>,
+}
+
+pub fn run() -> i32 {
+    let mut input = String::new();
+    if let Err(err) = std::io::stdin().read_to_string(&mut input) {
+        eprintln!("{}", err);
+        return 2;
+    }
+    let output = match process(&input) {
+        Ok(output) => output,
+        Err(err) => {
+            eprintln!("{}", err);
+            return 2;
+        }
+    };
+    let mut stdout = std::io::stdout();
+    match writeln!(stdout, "{}", output) {
+        Ok(_) => 0,
+        Err(err) => {
+            eprintln!("{}", err);
+            1
+        }
+    }
+}
+
+fn process(input: &str) -> Result<String, serde_json::Error> {
+    let parts: Vec<Part> = serde_json::from_str(input)?;
+    let results: Vec<PartResult> = parts.iter().map(calculate_part).collect();
+    serde_json::to_string(&results)
+}
+
+pub fn calculate_part(part: &Part) -> PartResult {
+    match try_calculate_part(part) {
+        Ok(result) => PartResult {
+            label: part.label.clone(),
+            note: part.note.clone(),
+            area: Some(result.get_area()),
+            result: Some(result.get_result().to_string()),
+            error: None,
+        },
+        Err(err) => PartResult {
+            label: part.label.clone(),
+            note: part.note.clone(),
+            error: Some(literals::tr(err)),
+            ..Default::default()
+        },
+    }
+}
+
+fn try_calculate_part(part: &Part) -> Result<shapes::CalculationResult, &'static str> {
+    let input_units = match &part.input_units {
+        Some(unit) => unit.parse()?,
+        None => measure::LengthUnits::MM,
+    };
+    let output_units = match &part.output_units {
+        Some(unit) => unit.parse()?,
+        None => measure::AreaUnits::DM2,
+    };
+    let mut shapes = shapes::get_shapes();
+    let shape = shapes::find_shape(&mut shapes, &part.shape).ok_or(messages::SHAPE_FAIL)?;
+    let dimensions: Vec<String> = part.dimensions.iter().map(Value::text).collect();
+    let dimensions: Vec<&str> = dimensions.iter().map(String::as_str).collect();
+    let factor = part.factor.as_ref().map(Value::text).unwrap_or_default();
+    let options: Vec<&str> = part.options.iter().map(String::as_str).collect();
+    shape.fill_form(&dimensions, &factor, &options)?;
+    shape.calculate(
+        input_units.value(),
+        output_units.value(),
+        &NumberFormat::default(),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::process;
+    use serde_json::json;
+
+    fn process_json(input: serde_json::Value) -> serde_json::Value {
+        let output = process(&input.to_string()).unwrap();
+        serde_json::from_str(&output).unwrap()
+    }
+
+    #[test]
+    fn test_batch() {
+        let output = process_json(json!([
+            {"shape": "rectangle", "dimensions": [100, "200"], "label": "DRW-104"},
+            {"shape": "rectangle", "dimensions": ["1", "2"], "factor": 3,
+                "input_units": "m", "output_units": "m2"},
+        ]));
+        assert_eq!(output[0]["area"], json!(2.0));
+        assert_eq!(output[0]["error"], json!(null));
+        assert_eq!(output[0]["label"], json!("DRW-104"));
+        assert_eq!(output[1].get("label"), None);
+        assert_eq!(output[1]["area"], json!(6.0));
+        assert!(output[1]["result"].is_string());
+    }
+
+    #[test]
+    fn test_batch_errors() {
+        let output = process_json(json!([
+            {"shape": "triangle", "dimensions": [1]},
+            {"shape": "circle", "dimensions": [1], "output_units": "km2"},
+            {"shape": "circle", "dimensions": ["x"]},
+        ]));
+        for result in output.as_array().unwrap() {
+            assert_eq!(result["area"], json!(null));
+            assert!(result["error"].is_string());
+        }
+    }
+
+    #[test]
+    fn test_batch_malformed() {
+        assert!(process("{\"shape\": \"circle\"}").is_err());
+        assert!(process("[{\"dimensions\": [1]}]").is_err());
+    }
+}
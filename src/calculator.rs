@@ -1,14 +1,24 @@
-use crate::{literals, shapes};
 use arboard::Clipboard;
+use area_calculator::{
+    literals::{self, tr},
+    material, measure,
+    number_format::{self, NumberFormat},
+    shapes, CalculatorState,
+};
 use eframe::egui;
 use eframe::egui::Key;
 use egui_modal::Modal;
 
-use calculator_state::CalculatorState;
+use coating_panel::CoatingPanel;
 use literals::messages;
+use plating_panel::PlatingPanel;
+use pricing_panel::PricingPanel;
 
-mod calculator_state;
-mod measure;
+mod coating_panel;
+mod format_panel;
+mod plating_panel;
+mod preview;
+mod pricing_panel;
 
 //mod clipboard;
 
@@ -22,14 +32,31 @@ pub struct Calculator {
     state: CalculatorState,
     current: usize,
     flags: ViewFlags,
+    coating: CoatingPanel,
+    plating: PlatingPanel,
+    pricing: PricingPanel,
+    new_group: String,
+    bulk_factor: String,
+    multiplier: String,
+    /// Id of the result whose working is shown.
+    working: Option<u64>,
 }
 
 impl Default for Calculator {
     fn default() -> Self {
+        let mut state = CalculatorState::default();
+        state.set_number_format(NumberFormat::for_language(literals::language()));
         Self {
-            state: CalculatorState::default(),
+            state,
             current: 0,
             flags: ViewFlags::NoFlags,
+            coating: CoatingPanel::default(),
+            plating: PlatingPanel::default(),
+            pricing: PricingPanel::default(),
+            new_group: String::new(),
+            bulk_factor: String::new(),
+            multiplier: String::new(),
+            working: None,
         }
     }
 }
@@ -39,22 +66,235 @@ impl Calculator {
         self.state.calculate(self.current);
     }
 
+    fn copy(&mut self, text: String) {
+        let clipboard = Clipboard::new();
+        match clipboard {
+            Ok(mut buffer) => match buffer.set_text(text) {
+                Err(_) => {
+                    self.state.new_message(messages::BUFFER_FAIL);
+                }
+                Ok(_) => {
+                    self.state.new_message(messages::BUFFER_COPIED);
+                }
+            },
+            _ => {
+                self.state.new_message(messages::BUFFER_ERROR);
+            }
+        }
+    }
+
     fn calculation_list(&mut self, ui: &mut egui::Ui) {
-        self.state
-            .get_results()
-            .iter()
-            .enumerate()
-            .for_each(|(index, item)| {
-                ui.horizontal(|ui| {
-                    ui.label(item.get_result());
-                    if ui.add(egui::widgets::Button::new("⚙").small()).clicked() {
-                        self.flags = ViewFlags::Modal(index);
-                    }
-                    if ui.add(egui::widgets::Button::new("❌").small()).clicked() {
-                        self.flags = ViewFlags::Remove(index);
+        let grouped = self.state.get_groups().len() > 1;
+        for group in 0..self.state.get_groups().len() {
+            let items: Vec<usize> = self
+                .state
+                .get_results()
+                .iter()
+                .enumerate()
+                .filter(|(_, item)| item.get_group() == group)
+                .map(|(index, _)| index)
+                .collect();
+            if grouped && (!items.is_empty() || group == self.state.current_group()) {
+                ui.label(
+                    egui::RichText::new(format!(
+                        "{}: {}",
+                        self.state.get_groups()[group],
+                        self.state.get_str_subtotal(group)
+                    ))
+                    .strong(),
+                );
+            }
+            for index in items {
+                self.calculation_row(ui, index);
+            }
+        }
+    }
+
+    fn calculation_row(&mut self, ui: &mut egui::Ui, index: usize) {
+        let id = self.state.get_results()[index].get_id();
+        let row = ui.horizontal(|ui| {
+            let mut selected = self.state.is_selected(index);
+            if ui.checkbox(&mut selected, "").changed() {
+                self.state.set_selected(index, selected);
+            }
+            ui.dnd_drag_source(egui::Id::new(("result", index)), index, |ui| {
+                ui.label("☰");
+            })
+            .response
+            .on_hover_text(tr(literals::DRAG_TO_MOVE));
+            let item = &self.state.get_results()[index];
+            if !item.get_label().is_empty() {
+                ui.label(egui::RichText::new(item.get_label()).strong());
+            }
+            if !item.get_note().is_empty() {
+                ui.label(egui::RichText::new(item.get_note()).italics().weak());
+            }
+            ui.label(item.get_result());
+            if let Some(range) = self.state.get_str_range(index) {
+                ui.label(range).on_hover_text(tr(literals::AREA_RANGE));
+            }
+            if let Some(mass) = self.state.result_mass(index) {
+                ui.label(format!(
+                    "m={} {} {}",
+                    number_format::fixed(mass, 3, &self.state.number_format()),
+                    tr(literals::KG),
+                    tr(literals::PER_ITEM)
+                ));
+            }
+            if self.state.pricing().price > 0. {
+                if let Some(price) = self.state.result_price(index) {
+                    let price = number_format::fixed(price, 2, &self.state.number_format());
+                    ui.label(format!("= {}", price));
+                }
+            }
+            if ui
+                .add(
+                    egui::widgets::Button::new("ƒ")
+                        .small()
+                        .selected(self.working == Some(id)),
+                )
+                .on_hover_text(tr(literals::WORKING))
+                .clicked()
+            {
+                self.working = match self.working {
+                    Some(shown) if shown == id => None,
+                    _ => Some(id),
+                };
+            }
+            if ui.add(egui::widgets::Button::new("⚙").small()).clicked() {
+                self.state.begin_edit(index);
+                self.flags = ViewFlags::Modal(index);
+            }
+            if ui
+                .add(egui::widgets::Button::new("⧉").small())
+                .on_hover_text(tr(literals::DUPLICATE))
+                .clicked()
+            {
+                self.state.duplicate(index);
+            }
+            if ui.add(egui::widgets::Button::new("❌").small()).clicked() {
+                self.flags = ViewFlags::Remove(index);
+            }
+        });
+        if let Some(from) = row.response.dnd_release_payload::<usize>() {
+            self.state.move_result(*from, index);
+        }
+        if self.working == Some(id) {
+            ui.indent(("working", index), |ui| {
+                for line in self.state.result_breakdown(index) {
+                    ui.label(egui::RichText::new(line).weak());
+                }
+            });
+        }
+    }
+
+    fn bulk_toolbar(&mut self, ui: &mut egui::Ui) {
+        if self.state.get_results().is_empty() {
+            return;
+        }
+        let count = self.state.selected_indices().len();
+        ui.horizontal(|ui| {
+            ui.label("×");
+            ui.add(
+                egui::TextEdit::singleline(&mut self.multiplier).desired_width(literals::STEP * 2.),
+            );
+            if ui
+                .button(tr(literals::MULTIPLY_FACTOR))
+                .on_hover_text(tr(literals::MULTIPLY_FACTOR_HINT))
+                .clicked()
+                && !self.multiplier.trim().is_empty()
+            {
+                self.state.multiply_factors(&self.multiplier);
+            }
+        });
+        if count == 0 {
+            return;
+        }
+        ui.horizontal(|ui| {
+            ui.label(format!("{}: {}", tr(literals::SELECTED), count));
+            ui.add(
+                egui::TextEdit::singleline(&mut self.bulk_factor)
+                    .desired_width(literals::STEP * 2.),
+            );
+            if ui.button(tr(literals::SET_FACTOR)).clicked() && !self.bulk_factor.trim().is_empty()
+            {
+                self.state.set_selected_factor(&self.bulk_factor);
+            }
+            if ui.button(tr(literals::DELETE_SELECTED)).clicked() {
+                self.state.remove_selected();
+            }
+        });
+    }
+
+    fn list_toolbar(&mut self, ui: &mut egui::Ui) {
+        ui.horizontal(|ui| {
+            if ui
+                .add_enabled(
+                    self.state.can_undo(),
+                    egui::widgets::Button::new("⟲").small(),
+                )
+                .on_hover_text(tr(literals::UNDO))
+                .clicked()
+            {
+                self.state.undo();
+            }
+            if ui
+                .add_enabled(
+                    self.state.can_redo(),
+                    egui::widgets::Button::new("⟳").small(),
+                )
+                .on_hover_text(tr(literals::REDO))
+                .clicked()
+            {
+                self.state.redo();
+            }
+            if ui
+                .add_enabled(
+                    !self.state.get_results().is_empty(),
+                    egui::widgets::Button::new("📋").small(),
+                )
+                .on_hover_text(tr(literals::COPY_LIST))
+                .clicked()
+            {
+                self.copy(self.state.results_text());
+            }
+            let mut current = self.state.current_group();
+            egui::ComboBox::from_label(tr(literals::GROUP))
+                .selected_text(self.state.get_groups()[current].as_str())
+                .show_ui(ui, |ui| {
+                    for (index, name) in self.state.get_groups().iter().enumerate() {
+                        ui.selectable_value(&mut current, index, name.as_str());
                     }
                 });
-            })
+            self.state.select_group(current);
+            ui.add(
+                egui::TextEdit::singleline(&mut self.new_group).desired_width(literals::STEP * 3.),
+            );
+            if ui.add(egui::widgets::Button::new("➕").small()).clicked()
+                && !self.new_group.trim().is_empty()
+            {
+                self.state.add_group(&self.new_group);
+                self.new_group.clear();
+            }
+            if ui
+                .add_enabled(
+                    !self.new_group.trim().is_empty(),
+                    egui::widgets::Button::new("✏").small(),
+                )
+                .on_hover_text(tr(literals::RENAME_GROUP))
+                .clicked()
+            {
+                self.state.rename_group(current, &self.new_group);
+                self.new_group.clear();
+            }
+            if ui
+                .add_enabled(current > 0, egui::widgets::Button::new("❌").small())
+                .on_hover_text(tr(literals::DELETE_GROUP))
+                .clicked()
+            {
+                self.state.delete_group(current);
+            }
+        });
     }
 
     fn shape_chooser(&mut self, ui: &mut egui::Ui) {
@@ -78,62 +318,144 @@ impl Calculator {
     fn measure_units(&mut self, ui: &mut egui::Ui) {
         ui.horizontal(|ui| {
             let (mut current_input, mut current_output) = self.state.current_units();
-            egui::ComboBox::from_label(literals::INPUT_UNITS)
+            egui::ComboBox::from_label(tr(literals::INPUT_UNITS))
                 .selected_text(current_input.name())
                 .show_ui(ui, |ui| {
-                    ui.selectable_value(&mut current_input, measure::LengthUnits::MM, literals::MM);
-                    ui.selectable_value(&mut current_input, measure::LengthUnits::SM, literals::SM);
-                    ui.selectable_value(&mut current_input, measure::LengthUnits::DM, literals::DM);
-                    ui.selectable_value(&mut current_input, measure::LengthUnits::M, literals::M);
+                    ui.selectable_value(
+                        &mut current_input,
+                        measure::LengthUnits::MM,
+                        tr(literals::MM),
+                    );
+                    ui.selectable_value(
+                        &mut current_input,
+                        measure::LengthUnits::SM,
+                        tr(literals::SM),
+                    );
+                    ui.selectable_value(
+                        &mut current_input,
+                        measure::LengthUnits::DM,
+                        tr(literals::DM),
+                    );
+                    ui.selectable_value(
+                        &mut current_input,
+                        measure::LengthUnits::M,
+                        tr(literals::M),
+                    );
                 });
             self.state.new_input_unit(current_input);
-            egui::ComboBox::from_label(literals::OUTPUT_UNITS)
+            egui::ComboBox::from_label(tr(literals::OUTPUT_UNITS))
                 .selected_text(current_output.name())
                 .show_ui(ui, |ui| {
                     ui.selectable_value(
                         &mut current_output,
                         measure::AreaUnits::MM2,
-                        literals::MM2,
+                        tr(literals::MM2),
                     );
                     ui.selectable_value(
                         &mut current_output,
                         measure::AreaUnits::SM2,
-                        literals::SM2,
+                        tr(literals::SM2),
                     );
                     ui.selectable_value(
                         &mut current_output,
                         measure::AreaUnits::DM2,
-                        literals::DM2,
+                        tr(literals::DM2),
+                    );
+                    ui.selectable_value(
+                        &mut current_output,
+                        measure::AreaUnits::M2,
+                        tr(literals::M2),
                     );
-                    ui.selectable_value(&mut current_output, measure::AreaUnits::M2, literals::M2);
                 });
             self.state.new_output_unit(current_output);
         });
     }
+
+    fn language(&mut self, ui: &mut egui::Ui) {
+        let mut current = literals::language();
+        egui::ComboBox::from_id_source("language")
+            .selected_text(current.name())
+            .show_ui(ui, |ui| {
+                for language in literals::Language::all() {
+                    ui.selectable_value(&mut current, language, language.name());
+                }
+            });
+        if current != literals::language() {
+            self.state.set_language(current);
+            ui.ctx()
+                .send_viewport_cmd(egui::ViewportCommand::Title(String::from(tr(
+                    literals::APP_TITLE,
+                ))));
+        }
+    }
+
+    fn material(&mut self, ui: &mut egui::Ui) {
+        let mut current = self.state.current_material();
+        egui::ComboBox::from_label(tr(literals::MATERIAL))
+            .selected_text(current.name())
+            .show_ui(ui, |ui| {
+                for material in material::Material::ALL {
+                    ui.selectable_value(&mut current, material, material.name());
+                }
+            });
+        self.state.new_material(current);
+    }
 }
 
-fn shape_input(shape: &mut [shapes::FormElement; 6], ui: &mut egui::Ui) {
+fn shape_input(shape: &mut [shapes::FormElement], ui: &mut egui::Ui) {
     for field in shape {
         match field {
-            shapes::FormElement::InputField(label, txt) => {
+            shapes::FormElement::InputField(label, txt)
+            | shapes::FormElement::OptionalField(label, txt) => {
                 ui.horizontal(|ui| {
                     ui.text_edit_singleline(txt)
-                        .labelled_by(ui.label(*label).id);
+                        .labelled_by(ui.label(tr(label)).id);
                 });
             }
             shapes::FormElement::CheckBox(label, state) => {
                 ui.horizontal(|ui| {
-                    ui.checkbox(state, *label);
+                    ui.checkbox(state, tr(label));
+                });
+            }
+            shapes::FormElement::Choice(label, options, selected) => {
+                egui::ComboBox::from_label(tr(label))
+                    .selected_text(tr(options[*selected]))
+                    .show_ui(ui, |ui| {
+                        for (index, option) in options.iter().enumerate() {
+                            ui.selectable_value(selected, index, tr(option));
+                        }
+                    });
+            }
+            shapes::FormElement::Count(label, count, range) => {
+                ui.horizontal(|ui| {
+                    ui.add(egui::DragValue::new(count).clamp_range(range.clone()))
+                        .labelled_by(ui.label(tr(label)).id);
                 });
             }
             shapes::FormElement::FactorField(txt) => {
                 ui.horizontal(|ui| {
                     ui.text_edit_singleline(txt)
-                        .labelled_by(ui.label(literals::FACTOR).id);
+                        .labelled_by(ui.label(tr(literals::FACTOR)).id);
                 });
             }
-            shapes::FormElement::NoElement => {
-                ui.add_visible(false, egui::TextEdit::singleline(&mut String::new()));
+            shapes::FormElement::Group(label, template, entries) => {
+                let mut removed = None;
+                for (index, entry) in entries.iter_mut().enumerate() {
+                    ui.push_id(index, |ui| {
+                        ui.horizontal(|ui| {
+                            if ui.add(egui::widgets::Button::new("❌").small()).clicked() {
+                                removed = Some(index);
+                            }
+                            ui.vertical(|ui| shape_input(entry, ui));
+                        });
+                    });
+                }
+                if let Some(index) = removed {
+                    entries.remove(index);
+                }
+                if ui.button(format!("+ {}", tr(label))).clicked() {
+                    entries.push(template.clone());
+                }
             }
         }
     }
@@ -141,6 +463,7 @@ fn shape_input(shape: &mut [shapes::FormElement; 6], ui: &mut egui::Ui) {
 
 impl eframe::App for Calculator {
     fn update(&mut self, ctx: &egui::Context, _frame: &mut eframe::Frame) {
+        let mut note_focused = false;
         egui::CentralPanel::default().show(ctx, |ui| {
             let spacing = ui.spacing().item_spacing.x;
             self.shape_chooser(ui);
@@ -148,10 +471,15 @@ impl eframe::App for Calculator {
                 egui::RichText::new(self.state.get_shapes()[self.current].name())
                     .size(literals::STEP / 2.),
             );
+            let key = self.state.get_shapes()[self.current].key();
+            let format = self.state.number_format();
             let shape = self.state.form_state(self.current);
             match shape {
                 Some(form) => {
-                    shape_input(form, ui);
+                    ui.horizontal(|ui| {
+                        ui.vertical(|ui| shape_input(form, ui));
+                        preview::show(ui, key, form, &format);
+                    });
                 }
                 None => {
                     for _i in 0..5 {
@@ -162,7 +490,7 @@ impl eframe::App for Calculator {
             ui.horizontal(|ui| {
                 if ui
                     .add(
-                        egui::widgets::Button::new(literals::CALCULATE).min_size(egui::vec2(
+                        egui::widgets::Button::new(tr(literals::CALCULATE)).min_size(egui::vec2(
                             literals::STEP * 9. + 2. * spacing,
                             literals::STEP * 0.5,
                         )),
@@ -175,7 +503,7 @@ impl eframe::App for Calculator {
             ui.horizontal(|ui| {
                 if ui
                     .add(
-                        egui::widgets::Button::new(literals::CLEAR).min_size(egui::vec2(
+                        egui::widgets::Button::new(tr(literals::CLEAR)).min_size(egui::vec2(
                             literals::STEP * 4.5 + spacing,
                             literals::STEP * 0.5,
                         )),
@@ -186,42 +514,64 @@ impl eframe::App for Calculator {
                 }
                 if ui
                     .add(
-                        egui::widgets::Button::new(literals::COPY)
+                        egui::widgets::Button::new(tr(literals::COPY))
                             .min_size(egui::vec2(literals::STEP * 4.5, literals::STEP * 0.5)),
                     )
                     .clicked()
                 {
-                    let clipboard = Clipboard::new();
-                    match clipboard {
-                        Ok(mut buffer) => match buffer.set_text(self.state.get_str_area()) {
-                            Err(_) => {
-                                self.state.new_message(messages::BUFFER_FAIL);
-                            }
-                            Ok(_) => {
-                                self.state.new_message(messages::BUFFER_COPIED);
-                            }
-                        },
-                        _ => {
-                            self.state.new_message(messages::BUFFER_ERROR);
-                        }
-                    }
+                    self.copy(self.state.get_str_area().to_string());
                 }
             });
             self.measure_units(ui);
             ui.label(
-                egui::RichText::new(format!("{} {}", literals::TOTAL, self.state.get_str_area()))
-                    .size(literals::STEP / 2.)
-                    .strong(),
+                egui::RichText::new(format!(
+                    "{} {}",
+                    tr(literals::TOTAL),
+                    self.state.get_str_area()
+                ))
+                .size(literals::STEP / 2.)
+                .strong(),
             );
+            if let Some(range) = self.state.get_str_area_range() {
+                ui.label(format!("{} {}", tr(literals::AREA_RANGE), range));
+            }
+            ui.horizontal(|ui| {
+                ui.label(format!(
+                    "{} {} {}",
+                    tr(literals::MASS),
+                    self.state.get_str_mass(),
+                    tr(literals::KG)
+                ));
+                self.material(ui);
+            });
+            egui::CollapsingHeader::new(tr(literals::COATING)).show(ui, |ui| {
+                let area = self.state.area_in(measure::AreaUnits::M2);
+                self.coating.show(ui, area, &self.state.number_format());
+            });
+            egui::CollapsingHeader::new(tr(literals::PLATING)).show(ui, |ui| {
+                let area = self.state.area_in(measure::AreaUnits::DM2);
+                self.plating.show(ui, area, &self.state.number_format());
+            });
+            egui::CollapsingHeader::new(tr(literals::PRICING)).show(ui, |ui| {
+                self.pricing.show(ui, &mut self.state);
+            });
+            egui::CollapsingHeader::new(tr(literals::NUMBER_FORMAT)).show(ui, |ui| {
+                format_panel::show(ui, &mut self.state);
+            });
 
             egui::TopBottomPanel::bottom("status bar")
                 .resizable(false)
                 .min_height(0.)
                 .show_inside(ui, |ui| {
-                    ui.label(self.state.get_message(ui.ctx().input(|i| i.time)))
+                    ui.horizontal(|ui| {
+                        self.language(ui);
+                        ui.label(self.state.get_message(ui.ctx().input(|i| i.time)));
+                    })
                 });
 
             egui::CentralPanel::default().show_inside(ui, |ui| {
+                self.list_toolbar(ui);
+                self.bulk_toolbar(ui);
                 egui::ScrollArea::vertical()
                     .auto_shrink([false, false])
                     .show(ui, |ui| {
@@ -232,9 +582,33 @@ impl eframe::App for Calculator {
                 ViewFlags::Modal(index) => {
                     let modal = Modal::new(ctx, "edit_modal");
                     modal.show(|ui| {
-                        modal.title(ui, literals::EDIT);
+                        modal.title(ui, tr(literals::EDIT));
                         modal.frame(ui, |ui| {
                             ui.label(self.state.result_name(index));
+                            if let Some(result) = self.state.get_results().get(index) {
+                                let mut group = result.get_group();
+                                egui::ComboBox::from_label(tr(literals::GROUP))
+                                    .selected_text(self.state.get_groups()[group].as_str())
+                                    .show_ui(ui, |ui| {
+                                        for (i, name) in self.state.get_groups().iter().enumerate()
+                                        {
+                                            ui.selectable_value(&mut group, i, name.as_str());
+                                        }
+                                    });
+                                self.state.set_result_group(index, group);
+                            }
+                            if let Some((label, note)) = self.state.result_details(index) {
+                                ui.horizontal(|ui| {
+                                    ui.text_edit_singleline(label)
+                                        .labelled_by(ui.label(tr(literals::LABEL)).id);
+                                });
+                                ui.horizontal(|ui| {
+                                    note_focused = ui
+                                        .text_edit_multiline(note)
+                                        .labelled_by(ui.label(tr(literals::NOTE)).id)
+                                        .has_focus();
+                                });
+                            }
                             let shape = self.state.form_state_from_result(index);
                             match shape {
                                 Some(form) => {
@@ -244,13 +618,19 @@ impl eframe::App for Calculator {
                                     self.flags = ViewFlags::NoFlags;
                                 }
                             }
+                            let message = self.state.get_message(ui.input(|i| i.time));
+                            if !message.is_empty() {
+                                ui.colored_label(ui.visuals().error_fg_color, message);
+                            }
                         });
                         modal.buttons(ui, |ui| {
-                            if modal.button(ui, literals::CANCEL).clicked() {
+                            if modal.button(ui, tr(literals::CANCEL)).clicked() {
+                                self.state.cancel_edit();
                                 self.flags = ViewFlags::NoFlags;
                             }
-                            if modal.button(ui, literals::SAVE).clicked() {
-                                self.state.recalculate(index);
+                            if modal.button(ui, tr(literals::SAVE)).clicked()
+                                && self.state.recalculate(index)
+                            {
                                 self.flags = ViewFlags::NoFlags;
                             }
                         });
@@ -264,13 +644,24 @@ impl eframe::App for Calculator {
                 _ => {}
             }
         });
-        if ctx.input(|i| i.key_released(Key::Enter)) {
+        // Enter starts a new line in the note instead of saving the edit.
+        if !note_focused && ctx.input(|i| i.key_released(Key::Enter)) {
             if let ViewFlags::Modal(index) = self.flags {
-                self.state.recalculate(index);
-                self.flags = ViewFlags::NoFlags;
+                if self.state.recalculate(index) {
+                    self.flags = ViewFlags::NoFlags;
+                }
             } else {
                 self.calculate();
             }
         }
+        let no_focus = ctx.memory(|memory| memory.focused().is_none());
+        if no_focus && matches!(self.flags, ViewFlags::NoFlags) {
+            let redo_shortcut = egui::Modifiers::COMMAND | egui::Modifiers::SHIFT;
+            if ctx.input_mut(|i| i.consume_key(redo_shortcut, Key::Z)) {
+                self.state.redo();
+            } else if ctx.input_mut(|i| i.consume_key(egui::Modifiers::COMMAND, Key::Z)) {
+                self.state.undo();
+            }
+        }
     }
 }
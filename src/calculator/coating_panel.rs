@@ -0,0 +1,81 @@
+use area_calculator::{
+    coating,
+    literals::{self, tr},
+    number_format::{self, NumberFormat},
+    parser,
+};
+use eframe::egui;
+
+pub struct CoatingPanel {
+    coating: coating::Coating,
+    coverage: String,
+    thickness: String,
+    solids: String,
+    transfer_efficiency: String,
+}
+
+impl Default for CoatingPanel {
+    fn default() -> Self {
+        Self {
+            coating: coating::Coating::Paint,
+            coverage: String::new(),
+            thickness: String::new(),
+            solids: String::new(),
+            transfer_efficiency: String::from("100"),
+        }
+    }
+}
+
+impl CoatingPanel {
+    pub fn show(&mut self, ui: &mut egui::Ui, area: f64, format: &NumberFormat) {
+        egui::ComboBox::from_label(tr(literals::COATING_TYPE))
+            .selected_text(self.coating.name())
+            .show_ui(ui, |ui| {
+                for coating in coating::Coating::ALL {
+                    ui.selectable_value(&mut self.coating, coating, coating.name());
+                }
+            });
+        for (label, txt) in [
+            (tr(literals::COVERAGE), &mut self.coverage),
+            (tr(literals::FILM_THICKNESS), &mut self.thickness),
+            (self.coating.solids_label(), &mut self.solids),
+            (
+                tr(literals::TRANSFER_EFFICIENCY),
+                &mut self.transfer_efficiency,
+            ),
+        ] {
+            ui.horizontal(|ui| {
+                ui.text_edit_singleline(txt).labelled_by(ui.label(label).id);
+            });
+        }
+        let line = match self.calculate(area, format) {
+            Some(value) => format!(
+                "{} {} {}",
+                tr(literals::CONSUMPTION),
+                number_format::fixed(value, 2, format),
+                self.coating.unit()
+            ),
+            None => format!("{} —", tr(literals::CONSUMPTION)),
+        };
+        ui.label(line);
+    }
+
+    /// Coverage rate takes precedence over film thickness and solids.
+    fn calculate(&self, area: f64, format: &NumberFormat) -> Option<f64> {
+        let coverage = match parser::split_numbers(&self.coverage, format) {
+            Ok(coverage) if !self.coverage.trim().is_empty() => coverage,
+            _ => {
+                let thickness = parser::split_numbers(&self.thickness, format).ok()?;
+                let solids = parser::split_numbers(&self.solids, format).ok()?;
+                self.coating.coverage(thickness, solids)
+            }
+        };
+        let transfer_efficiency =
+            parser::split_numbers(&self.transfer_efficiency, format).ok()? / 100.;
+        let value = coating::consumption(area, coverage, transfer_efficiency);
+        if area <= 0. || !value.is_finite() || value <= 0. {
+            return None;
+        }
+        Some(value)
+    }
+}
@@ -0,0 +1,75 @@
+use area_calculator::number_format::{DecimalSeparator, Grouping, Precision, Rounding};
+use area_calculator::{
+    literals::{self, tr},
+    CalculatorState,
+};
+use eframe::egui;
+
+/// Number format of the results and totals.
+pub fn show(ui: &mut egui::Ui, state: &mut CalculatorState) {
+    let mut format = state.number_format();
+    let (mut significant, mut digits) = match format.precision {
+        Precision::Significant(digits) => (true, digits),
+        Precision::Decimals(digits) => (false, digits),
+    };
+    ui.horizontal(|ui| {
+        ui.radio_value(&mut significant, false, tr(literals::DECIMALS));
+        ui.radio_value(&mut significant, true, tr(literals::SIGNIFICANT));
+        ui.add(egui::DragValue::new(&mut digits).clamp_range(0..=12));
+    });
+    format.precision = if significant {
+        Precision::Significant(digits.max(1))
+    } else {
+        Precision::Decimals(digits)
+    };
+    egui::ComboBox::from_label(tr(literals::ROUNDING))
+        .selected_text(rounding_name(format.rounding))
+        .show_ui(ui, |ui| {
+            for rounding in Rounding::ALL {
+                ui.selectable_value(&mut format.rounding, rounding, rounding_name(rounding));
+            }
+        });
+    egui::ComboBox::from_label(tr(literals::DECIMAL_SEPARATOR))
+        .selected_text(decimal_name(format.decimal))
+        .show_ui(ui, |ui| {
+            for decimal in DecimalSeparator::ALL {
+                ui.selectable_value(&mut format.decimal, decimal, decimal_name(decimal));
+            }
+        });
+    egui::ComboBox::from_label(tr(literals::GROUPING))
+        .selected_text(grouping_name(format.grouping))
+        .show_ui(ui, |ui| {
+            for grouping in Grouping::ALL {
+                ui.selectable_value(&mut format.grouping, grouping, grouping_name(grouping));
+            }
+        });
+    if format != state.number_format() {
+        state.set_number_format(format);
+    }
+}
+
+fn rounding_name(rounding: Rounding) -> &'static str {
+    match rounding {
+        Rounding::HalfUp => tr(literals::HALF_UP),
+        Rounding::HalfEven => tr(literals::HALF_EVEN),
+        Rounding::Down => tr(literals::ROUND_DOWN),
+        Rounding::Up => tr(literals::ROUND_UP),
+    }
+}
+
+fn grouping_name(grouping: Grouping) -> &'static str {
+    match grouping {
+        Grouping::None => tr(literals::NO_GROUPING),
+        Grouping::Space => "1 000",
+        Grouping::Apostrophe => "1'000",
+        Grouping::Comma => "1,000",
+        Grouping::Dot => "1.000",
+    }
+}
+
+fn decimal_name(decimal: DecimalSeparator) -> &'static str {
+    match decimal {
+        DecimalSeparator::Dot => "0.5",
+        DecimalSeparator::Comma => "0,5",
+    }
+}
@@ -0,0 +1,90 @@
+use area_calculator::{
+    literals::{self, tr},
+    number_format::{self, NumberFormat},
+    parser, plating,
+};
+use eframe::egui;
+
+pub struct PlatingPanel {
+    metal: plating::PlatingMetal,
+    current_density: String,
+    thickness: String,
+    efficiency: String,
+}
+
+impl Default for PlatingPanel {
+    fn default() -> Self {
+        Self {
+            metal: plating::PlatingMetal::Zinc,
+            current_density: String::new(),
+            thickness: String::new(),
+            efficiency: String::from("100"),
+        }
+    }
+}
+
+impl PlatingPanel {
+    pub fn show(&mut self, ui: &mut egui::Ui, area: f64, format: &NumberFormat) {
+        egui::ComboBox::from_label(tr(literals::METAL))
+            .selected_text(self.metal.name())
+            .show_ui(ui, |ui| {
+                for metal in plating::PlatingMetal::ALL {
+                    ui.selectable_value(&mut self.metal, metal, metal.name());
+                }
+            });
+        for (label, txt) in [
+            (tr(literals::CURRENT_DENSITY), &mut self.current_density),
+            (tr(literals::THICKNESS), &mut self.thickness),
+            (tr(literals::EFFICIENCY), &mut self.efficiency),
+        ] {
+            ui.horizontal(|ui| {
+                ui.text_edit_singleline(txt).labelled_by(ui.label(label).id);
+            });
+        }
+        let result = self.calculate(area, format);
+        for (label, value, unit) in [
+            (
+                tr(literals::CURRENT),
+                result.map(|r| r.current),
+                tr(literals::AMPERE),
+            ),
+            (
+                tr(literals::TIME),
+                result.map(|r| r.time / 60.),
+                tr(literals::MINUTE),
+            ),
+            (
+                tr(literals::METAL_CONSUMPTION),
+                result.map(|r| r.metal_mass),
+                tr(literals::GRAM),
+            ),
+        ] {
+            let line = match value {
+                Some(value) => format!(
+                    "{} {} {}",
+                    label,
+                    number_format::fixed(value, 2, format),
+                    unit
+                ),
+                None => format!("{} —", label),
+            };
+            ui.label(line);
+        }
+    }
+
+    fn calculate(&self, area: f64, format: &NumberFormat) -> Option<plating::PlatingResult> {
+        let current_density = parser::split_numbers(&self.current_density, format).ok()?;
+        let thickness = parser::split_numbers(&self.thickness, format).ok()?;
+        let efficiency = parser::split_numbers(&self.efficiency, format).ok()? / 100.;
+        if area <= 0. || current_density <= 0. || thickness <= 0. || efficiency <= 0. {
+            return None;
+        }
+        Some(plating::calculate(
+            self.metal,
+            area,
+            current_density,
+            thickness,
+            efficiency,
+        ))
+    }
+}
@@ -0,0 +1,384 @@
+use area_calculator::{literals, number_format::NumberFormat, parser, shapes::FormElement};
+use eframe::egui::{self, Align2, Pos2, Rect, Shape, Stroke, Vec2};
+
+const SIZE: f32 = literals::STEP * 2.6;
+const MARGIN: f32 = literals::STEP * 0.35;
+/// Shapes with a sketch, fasteners and custom shapes have none.
+const SKETCHED: [&str; 7] = [
+    "circle",
+    "rectangle",
+    "cylinder",
+    "hexagon",
+    "hexagon_prism",
+    "bushing",
+    "cuboid",
+];
+
+/// Schematic of the shape with the dimension letters of its result line,
+/// in the proportions typed into the form. Takes no space for a shape
+/// without a sketch.
+pub fn show(ui: &mut egui::Ui, key: &str, form: &[FormElement], format: &NumberFormat) {
+    if !SKETCHED.contains(&key) {
+        return;
+    }
+    let (response, painter) = ui.allocate_painter(Vec2::splat(SIZE), egui::Sense::hover());
+    let sketch = Sketch {
+        painter,
+        area: response.rect.shrink(MARGIN),
+        stroke: Stroke::new(1.5, ui.visuals().text_color()),
+        thin: Stroke::new(1., ui.visuals().weak_text_color()),
+        font: egui::FontId::proportional(literals::STEP * 0.28),
+    };
+    let dims = Dimensions::new(form, format);
+    let option = form
+        .iter()
+        .find_map(|field| match field {
+            FormElement::CheckBox(_, state) => Some(*state),
+            _ => None,
+        })
+        .unwrap_or(false);
+    match key {
+        "circle" => sketch.circle(dims.get(0, 1.)),
+        "rectangle" => sketch.rectangle(dims.get(0, 1.5), dims.get(1, 1.)),
+        "cylinder" => sketch.cylinder(dims.get(0, 1.), dims.get(1, 1.5), option),
+        "hexagon" => sketch.hexagon(dims.get(0, 1.), option),
+        "hexagon_prism" => sketch.hexagon_prism(dims.get(0, 1.), dims.get(1, 1.5), option),
+        "bushing" => sketch.bushing(dims.get(0, 1.), dims.get(1, 0.5), dims.get(2, 1.)),
+        "cuboid" => sketch.cuboid(dims.get(0, 1.), dims.get(1, 0.8), dims.get(2, 1.5)),
+        _ => {}
+    }
+}
+
+/// Typed dimensions, missing ones are in proportion to the largest typed.
+struct Dimensions {
+    values: Vec<Option<f64>>,
+    reference: f64,
+}
+
+impl Dimensions {
+    fn new(form: &[FormElement], format: &NumberFormat) -> Self {
+        let values: Vec<Option<f64>> = form
+            .iter()
+            .filter_map(|field| match field {
+                FormElement::InputField(_, txt) => Some(
+                    parser::split_range(txt, format)
+                        .ok()
+                        .map(|range| range.nominal.abs())
+                        .filter(|value| *value > 0.),
+                ),
+                _ => None,
+            })
+            .collect();
+        let reference = values
+            .iter()
+            .flatten()
+            .fold(0., |max: f64, value| max.max(*value));
+        Self {
+            values,
+            reference: if reference > 0. { reference } else { 1. },
+        }
+    }
+
+    fn get(&self, index: usize, ratio: f64) -> f32 {
+        let value = self
+            .values
+            .get(index)
+            .copied()
+            .flatten()
+            .unwrap_or(ratio * self.reference);
+        (value / self.reference) as f32
+    }
+}
+
+struct Sketch {
+    painter: egui::Painter,
+    area: Rect,
+    stroke: Stroke,
+    thin: Stroke,
+    font: egui::FontId,
+}
+
+impl Sketch {
+    /// Rectangle of the given proportions fitted into the drawing area.
+    fn fit(&self, width: f32, height: f32) -> Rect {
+        let scale = (self.area.width() / width).min(self.area.height() / height);
+        Rect::from_center_size(self.area.center(), Vec2::new(width, height) * scale)
+    }
+
+    fn label(&self, pos: Pos2, align: Align2, text: &str) {
+        self.painter
+            .text(pos, align, text, self.font.clone(), self.thin.color);
+    }
+
+    /// Dimension line between two points with a letter beside it.
+    fn dimension(&self, from: Pos2, to: Pos2, offset: Vec2, text: &str) {
+        let (from, to) = (from + offset, to + offset);
+        let middle = from + (to - from) / 2.;
+        self.painter.arrow(middle, from - middle, self.thin);
+        self.painter.arrow(middle, to - middle, self.thin);
+        let align = if offset.x < 0. {
+            Align2::RIGHT_CENTER
+        } else if offset.x > 0. {
+            Align2::LEFT_CENTER
+        } else if offset.y < 0. {
+            Align2::CENTER_BOTTOM
+        } else {
+            Align2::CENTER_TOP
+        };
+        self.label(middle + offset.normalized() * 3., align, text);
+    }
+
+    fn ellipse(&self, center: Pos2, radius: Vec2, stroke: Stroke) {
+        let points = (0..48)
+            .map(|step| {
+                let angle = step as f32 / 48. * std::f32::consts::TAU;
+                center + Vec2::new(angle.cos() * radius.x, angle.sin() * radius.y)
+            })
+            .collect();
+        self.painter.add(Shape::closed_line(points, stroke));
+    }
+
+    fn circle(&self, d: f32) {
+        let rect = self.fit(d, d);
+        let radius = rect.width() / 2.;
+        self.painter
+            .circle_stroke(rect.center(), radius, self.stroke);
+        self.dimension(rect.left_center(), rect.right_center(), Vec2::ZERO, "");
+        self.label(rect.center(), Align2::CENTER_BOTTOM, "d");
+    }
+
+    fn rectangle(&self, l: f32, h: f32) {
+        let rect = self.fit(l, h);
+        self.painter.rect_stroke(rect, 0., self.stroke);
+        self.dimension(
+            rect.left_bottom(),
+            rect.right_bottom(),
+            Vec2::new(0., 6.),
+            "l",
+        );
+        self.dimension(rect.left_top(), rect.left_bottom(), Vec2::new(-6., 0.), "h");
+    }
+
+    fn cylinder(&self, d: f32, h: f32, threaded: bool) {
+        let rect = self.fit(d, h + d / 3.);
+        let radius = Vec2::new(rect.width() / 2., rect.width() / 6.);
+        let top = rect.center_top() + Vec2::new(0., radius.y);
+        let bottom = rect.center_bottom() - Vec2::new(0., radius.y);
+        self.ellipse(top, radius, self.stroke);
+        self.ellipse(bottom, radius, self.thin);
+        for side in [-radius.x, radius.x] {
+            let line = if threaded {
+                zigzag(
+                    top + Vec2::new(side, 0.),
+                    bottom + Vec2::new(side, 0.),
+                    side / 12.,
+                )
+            } else {
+                vec![top + Vec2::new(side, 0.), bottom + Vec2::new(side, 0.)]
+            };
+            self.painter.add(Shape::line(line, self.stroke));
+        }
+        self.dimension(
+            top - Vec2::new(radius.x, 0.),
+            top + Vec2::new(radius.x, 0.),
+            Vec2::ZERO,
+            "",
+        );
+        self.label(top, Align2::CENTER_BOTTOM, "d");
+        self.dimension(
+            top - Vec2::new(radius.x, 0.),
+            bottom - Vec2::new(radius.x, 0.),
+            Vec2::new(-6., 0.),
+            "h",
+        );
+    }
+
+    /// Hexagon with the circle its diameter refers to: across corners when
+    /// circumscribed, across flats otherwise.
+    fn hexagon_at(&self, rect: Rect, circumscribed: bool) {
+        let center = rect.center();
+        let radius = rect.width() / 2.;
+        let corner = if circumscribed {
+            radius
+        } else {
+            radius * 2. / 3f32.sqrt()
+        };
+        let points = (0..6)
+            .map(|step| {
+                let angle = step as f32 * std::f32::consts::FRAC_PI_3;
+                center + Vec2::new(angle.cos(), angle.sin()) * corner
+            })
+            .collect();
+        self.painter.add(Shape::closed_line(points, self.stroke));
+        self.painter.add(Shape::dashed_line(
+            &circle_points(center, radius),
+            self.thin,
+            4.,
+            3.,
+        ));
+        if circumscribed {
+            // Across corners
+            self.dimension(
+                center - Vec2::new(radius, 0.),
+                center + Vec2::new(radius, 0.),
+                Vec2::ZERO,
+                "",
+            );
+            self.label(center, Align2::CENTER_BOTTOM, "D");
+        } else {
+            // Across flats
+            self.dimension(
+                center - Vec2::new(0., radius),
+                center + Vec2::new(0., radius),
+                Vec2::ZERO,
+                "",
+            );
+            self.label(center + Vec2::new(3., 0.), Align2::LEFT_CENTER, "d");
+        }
+    }
+
+    fn hexagon(&self, d: f32, circumscribed: bool) {
+        let across_corners = if circumscribed {
+            d
+        } else {
+            d * 2. / 3f32.sqrt()
+        };
+        let rect = self.fit(across_corners, across_corners);
+        self.hexagon_at(
+            Rect::from_center_size(
+                rect.center(),
+                Vec2::splat(rect.width() * d / across_corners),
+            ),
+            circumscribed,
+        );
+    }
+
+    fn hexagon_prism(&self, d: f32, h: f32, circumscribed: bool) {
+        let across_corners = if circumscribed {
+            d
+        } else {
+            d * 2. / 3f32.sqrt()
+        };
+        let gap = across_corners / 4.;
+        let rect = self.fit(across_corners * 2. + gap, across_corners.max(h));
+        let scale = rect.height() / across_corners.max(h);
+        let top_view = Rect::from_min_size(
+            Pos2::new(rect.left(), rect.center().y - across_corners * scale / 2.),
+            Vec2::splat(across_corners * scale),
+        );
+        self.hexagon_at(
+            Rect::from_center_size(top_view.center(), Vec2::splat(d * scale)),
+            circumscribed,
+        );
+        let side = Rect::from_center_size(
+            Pos2::new(rect.right() - across_corners * scale / 2., rect.center().y),
+            Vec2::new(across_corners * scale, h * scale),
+        );
+        self.painter.rect_stroke(side, 0., self.stroke);
+        self.painter
+            .line_segment([side.center_top(), side.center_bottom()], self.stroke);
+        self.dimension(
+            side.right_top(),
+            side.right_bottom(),
+            Vec2::new(6., 0.),
+            "h",
+        );
+    }
+
+    /// Section of the bushing along its axis.
+    fn bushing(&self, outer: f32, inner: f32, h: f32) {
+        let rect = self.fit(outer, h);
+        self.painter.rect_stroke(rect, 0., self.stroke);
+        let hole = rect.width() * (inner / outer).min(1.) / 2.;
+        for side in [-hole, hole] {
+            self.painter.add(Shape::dashed_line(
+                &[
+                    rect.center_top() + Vec2::new(side, 0.),
+                    rect.center_bottom() + Vec2::new(side, 0.),
+                ],
+                self.thin,
+                4.,
+                3.,
+            ));
+        }
+        self.dimension(rect.left_top(), rect.right_top(), Vec2::new(0., -6.), "D");
+        self.dimension(
+            rect.center() - Vec2::new(hole, 0.),
+            rect.center() + Vec2::new(hole, 0.),
+            Vec2::ZERO,
+            "",
+        );
+        self.label(rect.center(), Align2::CENTER_BOTTOM, "d");
+        self.dimension(
+            rect.right_top(),
+            rect.right_bottom(),
+            Vec2::new(6., 0.),
+            "h",
+        );
+    }
+
+    /// Oblique projection: `a` vertical, `b` receding, `c` horizontal.
+    fn cuboid(&self, a: f32, b: f32, c: f32) {
+        let depth = Vec2::new(0.5, -0.5) * b;
+        let rect = self.fit(c + depth.x, a - depth.y);
+        let scale = rect.width() / (c + depth.x);
+        let depth = depth * scale;
+        let front = Rect::from_min_size(
+            rect.left_top() + Vec2::new(0., -depth.y),
+            Vec2::new(c, a) * scale,
+        );
+        self.painter.rect_stroke(front, 0., self.stroke);
+        let back = front.translate(depth);
+        for (from, to) in [
+            (front.left_top(), back.left_top()),
+            (front.right_top(), back.right_top()),
+            (front.right_bottom(), back.right_bottom()),
+            (back.left_top(), back.right_top()),
+            (back.right_top(), back.right_bottom()),
+        ] {
+            self.painter.line_segment([from, to], self.stroke);
+        }
+        self.dimension(
+            front.left_top(),
+            front.left_bottom(),
+            Vec2::new(-6., 0.),
+            "a",
+        );
+        self.dimension(
+            front.right_bottom(),
+            back.right_bottom(),
+            Vec2::new(6., 0.),
+            "b",
+        );
+        self.dimension(
+            front.left_bottom(),
+            front.right_bottom(),
+            Vec2::new(0., 6.),
+            "c",
+        );
+    }
+}
+
+fn circle_points(center: Pos2, radius: f32) -> Vec<Pos2> {
+    (0..=48)
+        .map(|step| {
+            let angle = step as f32 / 48. * std::f32::consts::TAU;
+            center + Vec2::new(angle.cos(), angle.sin()) * radius
+        })
+        .collect()
+}
+
+/// Thread profile along a side of the cylinder.
+fn zigzag(from: Pos2, to: Pos2, depth: f32) -> Vec<Pos2> {
+    let teeth = 12;
+    (0..=teeth * 2)
+        .map(|step| {
+            let point = from + (to - from) * step as f32 / (teeth * 2) as f32;
+            if step % 2 == 1 {
+                point + Vec2::new(depth, 0.)
+            } else {
+                point
+            }
+        })
+        .collect()
+}
@@ -0,0 +1,64 @@
+use area_calculator::{
+    literals::{self, tr},
+    measure,
+    number_format::{self, NumberFormat},
+    parser, pricing, CalculatorState,
+};
+use eframe::egui;
+
+pub struct PricingPanel {
+    price: String,
+    min_charge: String,
+    setup_fee: String,
+    units: measure::AreaUnits,
+}
+
+impl Default for PricingPanel {
+    fn default() -> Self {
+        Self {
+            price: String::new(),
+            min_charge: String::new(),
+            setup_fee: String::new(),
+            units: measure::AreaUnits::DM2,
+        }
+    }
+}
+
+impl PricingPanel {
+    pub fn show(&mut self, ui: &mut egui::Ui, state: &mut CalculatorState) {
+        let (_, output_units) = state.current_units();
+        let format = state.number_format();
+        if self.units != output_units {
+            // The state converted the price to the new units
+            self.units = output_units;
+            if !self.price.is_empty() {
+                self.price = number_format::input_text(state.pricing().price, &format);
+            }
+        }
+        let price_label = format!("{} {}", tr(literals::PRICE), output_units.name());
+        for (label, txt) in [
+            (price_label.as_str(), &mut self.price),
+            (tr(literals::MIN_CHARGE), &mut self.min_charge),
+            (tr(literals::SETUP_FEE), &mut self.setup_fee),
+        ] {
+            ui.horizontal(|ui| {
+                ui.text_edit_singleline(txt).labelled_by(ui.label(label).id);
+            });
+        }
+        state.set_pricing(pricing::Pricing {
+            price: parse(&self.price, &format),
+            min_charge: parse(&self.min_charge, &format),
+            setup_fee: parse(&self.setup_fee, &format),
+        });
+        let total = format!(
+            "{} {}",
+            tr(literals::QUOTE_TOTAL),
+            number_format::fixed(state.quote_total(), 2, &format)
+        );
+        ui.label(egui::RichText::new(total).strong());
+    }
+}
+
+fn parse(input: &str, format: &NumberFormat) -> f64 {
+    parser::split_numbers(input, format).unwrap_or(0.)
+}
@@ -0,0 +1,944 @@
+use std::collections::HashSet;
+
+use crate::literals;
+use crate::literals::messages;
+use crate::material;
+use crate::measure;
+use crate::number_format;
+use crate::parser;
+use crate::pricing;
+use crate::shapes;
+use history::History;
+
+mod history;
+
+/// A list of calculated shapes with a running total in the selected units.
+pub struct CalculatorState {
+    shapes: Vec<Box<dyn shapes::AreaShape>>,
+    results: Vec<shapes::CalculationResult>,
+    groups: Vec<String>,
+    current_group: usize,
+    sum: f64,
+    volume: f64,
+    message: &'static str,
+    area: String,
+    mass: String,
+    timer: f64,
+    input_units: measure::LengthUnits,
+    output_units: measure::AreaUnits,
+    format: number_format::NumberFormat,
+    material: material::Material,
+    pricing: pricing::Pricing,
+    history: History<Snapshot>,
+    edit_backup: Option<(usize, shapes::CalculationResult)>,
+    /// Ids of the results selected for the bulk actions.
+    selected: HashSet<u64>,
+}
+
+/// Results list as recorded in the undo history.
+struct Snapshot {
+    results: Vec<shapes::CalculationResult>,
+    groups: Vec<String>,
+    current_group: usize,
+    output_units: measure::AreaUnits,
+}
+
+impl Default for CalculatorState {
+    fn default() -> Self {
+        Self {
+            shapes: shapes::get_shapes(),
+            results: Vec::new(),
+            groups: vec![String::from(literals::tr(literals::DEFAULT_GROUP))],
+            current_group: 0,
+            sum: 0.,
+            volume: 0.,
+            message: "",
+            area: String::from("0"),
+            mass: String::from("0"),
+            timer: -1.,
+            input_units: measure::LengthUnits::MM,
+            output_units: measure::AreaUnits::DM2,
+            format: number_format::NumberFormat::default(),
+            material: material::Material::Steel,
+            pricing: pricing::Pricing::default(),
+            history: History::default(),
+            edit_backup: None,
+            selected: HashSet::new(),
+        }
+    }
+}
+
+impl CalculatorState {
+    /// Calculates the shape at `index` of [`CalculatorState::get_shapes`]
+    /// and appends it to the results, errors go to the status message.
+    pub fn calculate(&mut self, index: usize) {
+        if index >= self.shapes.len() {
+            self.new_message(messages::SHAPE_FAIL);
+            return;
+        }
+        let result = self.shapes[index].calculate(
+            self.input_units.value(),
+            self.output_units.value(),
+            &self.format,
+        );
+        match result {
+            Ok(mut shape) => {
+                self.history.push(self.snapshot());
+                shape.set_group(self.current_group);
+                self.sum += shape.get_area();
+                self.volume += shape.get_volume().unwrap_or(0.);
+                self.results.push(shape);
+                self.update_area();
+            }
+            Err(err) => self.new_message(err),
+        }
+    }
+
+    /// Validated form of a shape, to be edited before [`Self::calculate`].
+    pub fn form_state(&mut self, index: usize) -> Option<&mut [shapes::FormElement]> {
+        let format = self.format;
+        self.shapes.get_mut(index).map(|shape| {
+            shape.validate_form(&format);
+            shape.form_state()
+        })
+    }
+
+    /// Validated form of a result, to be edited before [`Self::recalculate`].
+    pub fn form_state_from_result(&mut self, index: usize) -> Option<&mut [shapes::FormElement]> {
+        let format = self.format;
+        self.results.get_mut(index).map(|result| {
+            let shape = result.get_state();
+            shape.validate_form(&format);
+            shape.form_state()
+        })
+    }
+
+    /// Name of the shape at `index` of [`Self::get_shapes`] in the current language.
+    pub fn result_name(&self, index: usize) -> &str {
+        match self.shapes.get(index) {
+            Some(shape) => shape.name(),
+            None => " ",
+        }
+    }
+
+    /// Keeps a copy of a result before its form is edited, so that the edit
+    /// can be cancelled or undone.
+    pub fn begin_edit(&mut self, index: usize) {
+        self.edit_backup = self
+            .results
+            .get(index)
+            .map(|result| (index, result.clone()));
+    }
+
+    /// Restores the result saved by [`CalculatorState::begin_edit`].
+    pub fn cancel_edit(&mut self) {
+        if let Some((index, result)) = self.edit_backup.take() {
+            if index < self.results.len() {
+                self.results[index] = result;
+            }
+        }
+    }
+
+    /// Calculates a result again after its form was edited, returns `false`
+    /// and keeps the edit open when the form cannot be calculated.
+    pub fn recalculate(&mut self, result_index: usize) -> bool {
+        let old_area = self.results[result_index].get_area();
+        let old_volume = self.results[result_index].get_volume().unwrap_or(0.);
+        let result = self.results[result_index].get_state().calculate(
+            self.input_units.value(),
+            self.output_units.value(),
+            &self.format,
+        );
+        match result {
+            Ok(mut result) => {
+                let mut snapshot = self.snapshot();
+                match self.edit_backup.take() {
+                    Some((index, original)) if index == result_index => {
+                        snapshot.results[index] = original;
+                    }
+                    _ => {}
+                }
+                self.history.push(snapshot);
+                result.copy_details(&self.results[result_index]);
+                self.sum -= old_area;
+                self.sum += result.get_area();
+                self.volume -= old_volume;
+                self.volume += result.get_volume().unwrap_or(0.);
+                self.results[result_index] = result;
+                self.update_area();
+                true
+            }
+            Err(err) => {
+                self.new_message(err);
+                false
+            }
+        }
+    }
+
+    /// Label and note of a result for editing.
+    pub fn result_details(&mut self, index: usize) -> Option<(&mut String, &mut String)> {
+        self.results
+            .get_mut(index)
+            .map(|result| result.details_mut())
+    }
+
+    /// Calculated results in list order.
+    pub fn get_results(&self) -> &Vec<shapes::CalculationResult> {
+        &self.results
+    }
+
+    /// Shapes that can be calculated, built-in ones first.
+    pub fn get_shapes(&self) -> &Vec<Box<dyn shapes::AreaShape>> {
+        &self.shapes
+    }
+
+    /// Converts the results and totals to the area `unit`.
+    pub fn new_output_unit(&mut self, unit: measure::AreaUnits) {
+        if self.output_units == unit {
+            return;
+        }
+        let factor = self.output_units.value() / unit.value();
+        self.output_units = unit;
+        self.sum *= factor;
+        self.pricing.scale(factor);
+        self.results.iter_mut().for_each(|result| {
+            result.scale_area(factor);
+            result.update_result(self.input_units.value(), &self.format)
+        });
+        self.update_area();
+    }
+
+    /// Format of the result lines and totals, also used to read the input.
+    pub fn number_format(&self) -> number_format::NumberFormat {
+        self.format
+    }
+
+    /// Changes the number format and rebuilds the result lines.
+    pub fn set_number_format(&mut self, format: number_format::NumberFormat) {
+        self.format = format;
+        self.refresh_results();
+    }
+
+    /// Switches the interface language and rebuilds the result lines.
+    pub fn set_language(&mut self, language: literals::Language) {
+        let default_group = literals::tr(literals::DEFAULT_GROUP);
+        literals::set_language(language);
+        if self.groups[0] == default_group {
+            self.groups[0] = String::from(literals::tr(literals::DEFAULT_GROUP));
+        }
+        self.refresh_results();
+    }
+
+    fn refresh_results(&mut self) {
+        self.results
+            .iter_mut()
+            .for_each(|result| result.update_result(self.input_units.value(), &self.format));
+        self.update_area();
+    }
+
+    /// Total area converted to `units`.
+    pub fn area_in(&self, units: measure::AreaUnits) -> f64 {
+        self.sum * self.output_units.value() / units.value()
+    }
+
+    /// Input length and output area units.
+    pub fn current_units(&self) -> (measure::LengthUnits, measure::AreaUnits) {
+        (self.input_units, self.output_units)
+    }
+
+    /// Reads new input in the length `unit`, calculated areas do not change.
+    pub fn new_input_unit(&mut self, unit: measure::LengthUnits) {
+        if self.input_units == unit {
+            return;
+        }
+        self.input_units = unit;
+        self.results
+            .iter_mut()
+            .for_each(|result| result.update_result(unit.value(), &self.format))
+    }
+
+    /// Material of the mass calculation.
+    pub fn current_material(&self) -> material::Material {
+        self.material
+    }
+
+    /// Changes the material and the total mass.
+    pub fn new_material(&mut self, material: material::Material) {
+        if self.material == material {
+            return;
+        }
+        self.material = material;
+        self.update_area();
+    }
+
+    /// Mass of one item of a result in kg for the selected material, the
+    /// result line holds factor `k` items.
+    pub fn result_mass(&self, index: usize) -> Option<f64> {
+        let result = self.results.get(index)?;
+        if result.get_factor() == 0. {
+            return None;
+        }
+        result
+            .get_mass(self.material.density())
+            .map(|mass| mass / result.get_factor())
+    }
+
+    /// Formula and working of a result in the current units.
+    pub fn result_breakdown(&self, index: usize) -> Vec<String> {
+        self.results
+            .get(index)
+            .map(|result| result.get_breakdown(self.input_units, self.output_units, &self.format))
+            .unwrap_or_default()
+    }
+
+    /// Prices of the quote.
+    pub fn pricing(&self) -> pricing::Pricing {
+        self.pricing
+    }
+
+    /// Sets the prices, the area price is per current output unit.
+    pub fn set_pricing(&mut self, pricing: pricing::Pricing) {
+        self.pricing = pricing;
+    }
+
+    /// Price of a result line, see [`pricing::Pricing::line_price`].
+    pub fn result_price(&self, index: usize) -> Option<f64> {
+        self.results.get(index).map(|result| {
+            self.pricing
+                .line_price(result.get_area(), result.get_factor())
+        })
+    }
+
+    /// Price of all results with the setup fee.
+    pub fn quote_total(&self) -> f64 {
+        self.pricing.quote_total(
+            self.results
+                .iter()
+                .map(|result| (result.get_area(), result.get_factor())),
+        )
+    }
+
+    /// Names of the result groups, the first one is the default group.
+    pub fn get_groups(&self) -> &Vec<String> {
+        &self.groups
+    }
+
+    /// Group that new results are added to.
+    pub fn current_group(&self) -> usize {
+        self.current_group
+    }
+
+    /// Makes `group` the group new results are added to.
+    pub fn select_group(&mut self, group: usize) {
+        if group < self.groups.len() {
+            self.current_group = group;
+        }
+    }
+
+    /// Adds a named group and makes it current.
+    pub fn add_group(&mut self, name: &str) {
+        self.groups.push(name.trim().to_string());
+        self.current_group = self.groups.len() - 1;
+    }
+
+    /// Renames a group.
+    pub fn rename_group(&mut self, group: usize, name: &str) {
+        if let Some(group) = self.groups.get_mut(group) {
+            *group = name.trim().to_string();
+        }
+    }
+
+    /// Deletes a group, its results move to the first group, which cannot
+    /// be deleted.
+    pub fn delete_group(&mut self, group: usize) {
+        if group == 0 || group >= self.groups.len() {
+            return;
+        }
+        self.history.push(self.snapshot());
+        self.groups.remove(group);
+        for result in self.results.iter_mut() {
+            match result.get_group() {
+                index if index == group => result.set_group(0),
+                index if index > group => result.set_group(index - 1),
+                _ => {}
+            }
+        }
+        if self.current_group >= group {
+            self.current_group -= 1;
+        }
+    }
+
+    /// Moves a result into `group`, can be undone.
+    pub fn set_result_group(&mut self, index: usize, group: usize) {
+        let changed = self
+            .results
+            .get(index)
+            .is_some_and(|result| result.get_group() != group);
+        if group < self.groups.len() && changed {
+            self.history.push(self.snapshot());
+            self.results[index].set_group(group);
+        }
+    }
+
+    /// Total area of the results in `group`.
+    pub fn group_subtotal(&self, group: usize) -> f64 {
+        self.results
+            .iter()
+            .filter(|result| result.get_group() == group)
+            .fold(0., |sum, result| sum + result.get_area())
+    }
+
+    /// Subtotal of a group formatted like the total area.
+    pub fn get_str_subtotal(&self, group: usize) -> String {
+        number_format::number(self.group_subtotal(group), &self.format)
+    }
+
+    /// Removes every result and named group, can be undone.
+    pub fn clear(&mut self) {
+        if !self.results.is_empty() || self.groups.len() > 1 {
+            self.history.push(self.snapshot());
+        }
+        self.results.clear();
+        self.selected.clear();
+        self.groups.truncate(1);
+        self.current_group = 0;
+        self.sum = 0.;
+        self.volume = 0.;
+        self.update_area();
+    }
+
+    /// Removes a result, can be undone.
+    pub fn remove(&mut self, index: usize) {
+        if index >= self.results.len() {
+            return;
+        }
+        self.history.push(self.snapshot());
+        let result = self.results.remove(index);
+        self.sum -= result.get_area();
+        self.volume -= result.get_volume().unwrap_or(0.);
+        self.update_area();
+    }
+
+    /// Moves a result to the position of `to`, into the group of that result.
+    pub fn move_result(&mut self, from: usize, to: usize) {
+        if from == to || from >= self.results.len() || to >= self.results.len() {
+            return;
+        }
+        self.history.push(self.snapshot());
+        let group = self.results[to].get_group();
+        let mut result = self.results.remove(from);
+        result.set_group(group);
+        self.results.insert(to, result);
+    }
+
+    /// Inserts a copy of a result right after it.
+    pub fn duplicate(&mut self, index: usize) {
+        if index >= self.results.len() {
+            return;
+        }
+        self.history.push(self.snapshot());
+        let mut result = self.results[index].clone();
+        result.new_id();
+        self.sum += result.get_area();
+        self.volume += result.get_volume().unwrap_or(0.);
+        self.results.insert(index + 1, result);
+        self.update_area();
+    }
+
+    /// Selects a result for the bulk actions.
+    pub fn set_selected(&mut self, index: usize, selected: bool) {
+        if let Some(result) = self.results.get(index) {
+            if selected {
+                self.selected.insert(result.get_id());
+            } else {
+                self.selected.remove(&result.get_id());
+            }
+        }
+    }
+
+    /// Whether a result is selected for the bulk actions.
+    pub fn is_selected(&self, index: usize) -> bool {
+        self.results
+            .get(index)
+            .is_some_and(|result| self.selected.contains(&result.get_id()))
+    }
+
+    /// Indices of the selected results.
+    pub fn selected_indices(&self) -> Vec<usize> {
+        (0..self.results.len())
+            .filter(|index| self.is_selected(*index))
+            .collect()
+    }
+
+    /// Removes the selected results, can be undone.
+    pub fn remove_selected(&mut self) {
+        if self.selected_indices().is_empty() {
+            return;
+        }
+        self.history.push(self.snapshot());
+        let selected = std::mem::take(&mut self.selected);
+        self.results
+            .retain(|result| !selected.contains(&result.get_id()));
+        self.update_totals();
+    }
+
+    /// Sets the factor field of the selected results and calculates them again.
+    pub fn set_selected_factor(&mut self, factor: &str) {
+        let indices = self.selected_indices();
+        if indices.is_empty() {
+            return;
+        }
+        if let Err(err) = parser::split_numbers(factor, &self.format) {
+            self.new_message(err);
+            return;
+        }
+        self.history.push(self.snapshot());
+        for index in indices {
+            self.edit_result(index, |shape| {
+                shape.set_factor(factor);
+                Ok(())
+            });
+        }
+        self.update_totals();
+    }
+
+    /// Multiplies the factor of the selected results, or of all results
+    /// when nothing is selected, e.g. when the batch size changes.
+    pub fn multiply_factors(&mut self, multiplier: &str) {
+        let multiplier = match parser::split_numbers(multiplier, &self.format) {
+            Ok(multiplier) => multiplier,
+            Err(err) => {
+                self.new_message(err);
+                return;
+            }
+        };
+        let mut indices = self.selected_indices();
+        if indices.is_empty() {
+            indices = (0..self.results.len()).collect();
+        }
+        if indices.is_empty() {
+            return;
+        }
+        self.history.push(self.snapshot());
+        let format = self.format;
+        for index in indices {
+            self.edit_result(index, |shape| shape.multiply_factor(multiplier, &format));
+        }
+        self.update_totals();
+    }
+
+    /// Applies `edit` to a copy of the form of a result and calculates it,
+    /// the result and its form are left unchanged on error.
+    fn edit_result(
+        &mut self,
+        index: usize,
+        edit: impl FnOnce(&mut Box<dyn shapes::AreaShape>) -> Result<(), &'static str>,
+    ) {
+        let mut shape = self.results[index].get_state().duplicate();
+        let result = edit(&mut shape).and_then(|_| {
+            shape.calculate(
+                self.input_units.value(),
+                self.output_units.value(),
+                &self.format,
+            )
+        });
+        match result {
+            Ok(mut result) => {
+                result.copy_details(&self.results[index]);
+                self.results[index] = result;
+            }
+            Err(err) => self.new_message(err),
+        }
+    }
+
+    /// Restores the results list before the last change.
+    pub fn undo(&mut self) {
+        if let Some(snapshot) = self.history.undo(self.snapshot()) {
+            self.restore(snapshot);
+        }
+    }
+
+    /// Repeats the last change undone with [`Self::undo`].
+    pub fn redo(&mut self) {
+        if let Some(snapshot) = self.history.redo(self.snapshot()) {
+            self.restore(snapshot);
+        }
+    }
+
+    /// Whether there is a change to undo.
+    pub fn can_undo(&self) -> bool {
+        self.history.can_undo()
+    }
+
+    /// Whether there is an undone change to repeat.
+    pub fn can_redo(&self) -> bool {
+        self.history.can_redo()
+    }
+
+    fn snapshot(&self) -> Snapshot {
+        Snapshot {
+            results: self.results.clone(),
+            groups: self.groups.clone(),
+            current_group: self.current_group,
+            output_units: self.output_units,
+        }
+    }
+
+    /// Restores a snapshot, converting it to the current units.
+    fn restore(&mut self, snapshot: Snapshot) {
+        let factor = snapshot.output_units.value() / self.output_units.value();
+        self.results = snapshot.results;
+        self.groups = snapshot.groups;
+        self.current_group = snapshot.current_group;
+        self.edit_backup = None;
+        for result in self.results.iter_mut() {
+            result.scale_area(factor);
+            result.update_result(self.input_units.value(), &self.format);
+        }
+        self.update_totals();
+    }
+
+    fn update_totals(&mut self) {
+        self.sum = self
+            .results
+            .iter()
+            .fold(0., |sum, result| sum + result.get_area());
+        self.volume = self.results.iter().fold(0., |volume, result| {
+            volume + result.get_volume().unwrap_or(0.)
+        });
+        self.update_area();
+    }
+
+    /// Status message, cleared once `time` passes its timeout.
+    pub fn get_message(&mut self, time: f64) -> &'static str {
+        if time > self.timer {
+            self.timer = time;
+            self.message = "";
+        }
+        literals::tr(self.message)
+    }
+
+    /// Shows a message key in the status bar for five seconds.
+    pub fn new_message(&mut self, message: &'static str) {
+        self.message = message;
+        self.timer += 5.;
+    }
+
+    fn update_area(&mut self) {
+        self.area = number_format::number(self.sum, &self.format);
+        self.mass = number_format::fixed(self.volume * self.material.density(), 3, &self.format);
+    }
+
+    /// Results as tab separated lines of label, note, result line and
+    /// area, followed by the total, for pasting into a spreadsheet.
+    pub fn results_text(&self) -> String {
+        let cell = |text: &str| text.replace(['\t', '\n'], " ");
+        let mut lines: Vec<String> = self
+            .results
+            .iter()
+            .map(|result| {
+                format!(
+                    "{}\t{}\t{}\t{}",
+                    cell(result.get_label()),
+                    cell(result.get_note()),
+                    result.get_result(),
+                    number_format::number(result.get_area(), &self.format)
+                )
+            })
+            .collect();
+        lines.push(format!(
+            "{}\t\t\t{}",
+            literals::tr(literals::TOTAL),
+            self.area
+        ));
+        lines.join("\n")
+    }
+
+    /// Total area formatted for display.
+    pub fn get_str_area(&self) -> &str {
+        self.area.as_str()
+    }
+
+    /// Minimum and maximum total area, `None` when no result has a tolerance.
+    pub fn area_range(&self) -> Option<(f64, f64)> {
+        if self
+            .results
+            .iter()
+            .all(|result| result.get_range().is_none())
+        {
+            return None;
+        }
+        Some(self.results.iter().fold((0., 0.), |(min, max), result| {
+            let (result_min, result_max) = result
+                .get_range()
+                .unwrap_or((result.get_area(), result.get_area()));
+            (min + result_min, max + result_max)
+        }))
+    }
+
+    /// Total area range formatted for display, e.g. `"1.9…2.1"`.
+    pub fn get_str_area_range(&self) -> Option<String> {
+        self.area_range().map(|range| self.format_range(range))
+    }
+
+    /// Area range of a result formatted for display.
+    pub fn get_str_range(&self, index: usize) -> Option<String> {
+        self.results
+            .get(index)?
+            .get_range()
+            .map(|range| self.format_range(range))
+    }
+
+    /// Total mass in kg formatted for display.
+    pub fn get_str_mass(&self) -> &str {
+        self.mass.as_str()
+    }
+
+    fn format_range(&self, (min, max): (f64, f64)) -> String {
+        format!(
+            "{}…{}",
+            number_format::number(min, &self.format),
+            number_format::number(max, &self.format)
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::CalculatorState;
+
+    fn add_rectangle(state: &mut CalculatorState, a: &str, b: &str) {
+        let index = state
+            .get_shapes()
+            .iter()
+            .position(|shape| shape.key() == "rectangle")
+            .unwrap();
+        state.shapes[index].fill_form(&[a, b], "", &[]).unwrap();
+        state.calculate(index);
+    }
+
+    #[test]
+    fn test_result_mass_per_item() {
+        let mut state = CalculatorState::default();
+        let index = state
+            .get_shapes()
+            .iter()
+            .position(|shape| shape.key() == "cuboid")
+            .unwrap();
+        state.shapes[index]
+            .fill_form(&["10", "10", "10"], "2", &[])
+            .unwrap();
+        state.calculate(index);
+        let mass = state.result_mass(0).unwrap();
+        assert!((mass - 0.00785).abs() < 1e-12);
+        add_rectangle(&mut state, "100", "100");
+        assert_eq!(state.result_mass(1), None);
+    }
+
+    #[test]
+    fn test_results_text() {
+        let mut state = CalculatorState::default();
+        add_rectangle(&mut state, "100", "100");
+        add_rectangle(&mut state, "100", "200");
+        let (label, note) = state.result_details(1).unwrap();
+        label.push_str("D-12");
+        note.push_str("two\nlines");
+        let lines: Vec<Vec<String>> = state
+            .results_text()
+            .lines()
+            .map(|line| line.split('\t').map(String::from).collect())
+            .collect();
+        assert_eq!(lines.len(), 3);
+        assert_eq!(lines[0][..2], ["", ""]);
+        assert_eq!(lines[1][..2], ["D-12", "two lines"]);
+        assert_eq!(lines[1][2], state.get_results()[1].get_result());
+        assert_eq!(lines[1][3], "2");
+        assert_eq!(lines[2], ["Total:", "", "", "3"]);
+    }
+
+    #[test]
+    fn test_group_subtotals() {
+        let mut state = CalculatorState::default();
+        add_rectangle(&mut state, "100", "100");
+        state.add_group("Assembly B");
+        add_rectangle(&mut state, "100", "200");
+        add_rectangle(&mut state, "100", "300");
+        assert_eq!(state.group_subtotal(0), 1.);
+        assert_eq!(state.group_subtotal(1), 5.);
+        assert_eq!(state.get_str_area(), "6");
+        state.remove(0);
+        assert_eq!(state.group_subtotal(0), 0.);
+        assert_eq!(state.get_str_area(), "5");
+    }
+
+    #[test]
+    fn test_result_ids() {
+        let mut state = CalculatorState::default();
+        add_rectangle(&mut state, "100", "100");
+        add_rectangle(&mut state, "100", "200");
+        let id = state.get_results()[0].get_id();
+        state.duplicate(0);
+        assert_ne!(state.get_results()[1].get_id(), id);
+        state.move_result(0, 2);
+        assert_eq!(state.get_results()[2].get_id(), id);
+        state.recalculate(2);
+        assert_eq!(state.get_results()[2].get_id(), id);
+        state.undo();
+        state.undo();
+        assert_eq!(state.get_results()[0].get_id(), id);
+    }
+
+    #[test]
+    fn test_undo_result_group() {
+        let mut state = CalculatorState::default();
+        add_rectangle(&mut state, "100", "100");
+        state.add_group("Frames");
+        state.set_result_group(0, 1);
+        state.set_result_group(0, 1);
+        assert_eq!(state.get_results()[0].get_group(), 1);
+        state.undo();
+        assert_eq!(state.get_results()[0].get_group(), 0);
+        assert_eq!(state.get_groups().len(), 2);
+    }
+
+    #[test]
+    fn test_delete_group() {
+        let mut state = CalculatorState::default();
+        add_rectangle(&mut state, "100", "100");
+        state.add_group("Assembly B");
+        add_rectangle(&mut state, "100", "200");
+        state.add_group("Assembly C");
+        add_rectangle(&mut state, "100", "300");
+        state.rename_group(2, " Assembly D ");
+        state.delete_group(0);
+        state.delete_group(1);
+        assert_eq!(state.get_groups().len(), 2);
+        assert_eq!(state.get_groups()[1], "Assembly D");
+        assert_eq!(state.current_group(), 1);
+        assert_eq!(state.group_subtotal(0), 3.);
+        assert_eq!(state.group_subtotal(1), 3.);
+        state.undo();
+        assert_eq!(state.get_groups().len(), 3);
+        assert_eq!(state.group_subtotal(1), 2.);
+    }
+
+    #[test]
+    fn test_undo_clear() {
+        let mut state = CalculatorState::default();
+        add_rectangle(&mut state, "100", "100");
+        add_rectangle(&mut state, "100", "200");
+        state.clear();
+        assert_eq!(state.get_results().len(), 0);
+        state.undo();
+        assert_eq!(state.get_results().len(), 2);
+        assert_eq!(state.get_str_area(), "3");
+        state.redo();
+        assert_eq!(state.get_str_area(), "0");
+    }
+
+    #[test]
+    fn test_undo_clear_groups() {
+        let mut state = CalculatorState::default();
+        state.add_group("Frames");
+        state.clear();
+        assert_eq!(state.get_groups().len(), 1);
+        state.undo();
+        assert_eq!(state.get_groups().len(), 2);
+        assert_eq!(state.get_groups()[1], "Frames");
+    }
+
+    #[test]
+    fn test_bulk_operations() {
+        let mut state = CalculatorState::default();
+        add_rectangle(&mut state, "100", "100");
+        add_rectangle(&mut state, "100", "200");
+        state.duplicate(1);
+        assert_eq!(state.get_str_area(), "5");
+        state.set_selected(1, true);
+        state.set_selected(2, true);
+        state.set_selected_factor("3");
+        assert_eq!(state.get_str_area(), "13");
+        state.move_result(0, 2);
+        assert_eq!(state.get_results()[2].get_area(), 1.);
+        state.remove_selected();
+        assert_eq!(state.get_str_area(), "1");
+        state.undo();
+        assert_eq!(state.get_str_area(), "13");
+    }
+
+    #[test]
+    fn test_bulk_factor_error() {
+        let mut state = CalculatorState::default();
+        add_rectangle(&mut state, "100", "100");
+        state.set_selected(0, true);
+        state.duplicate(0);
+        assert!(state.is_selected(0) && !state.is_selected(1));
+        state.form_state_from_result(0).unwrap()[1] =
+            crate::shapes::FormElement::InputField("", String::from("1 2"));
+        state.set_selected_factor("3");
+        assert!(matches!(
+            &state.form_state_from_result(0).unwrap()[2],
+            crate::shapes::FormElement::FactorField(factor) if factor.is_empty()
+        ));
+        assert_eq!(state.get_str_area(), "2");
+    }
+
+    #[test]
+    fn test_multiply_factors() {
+        let mut state = CalculatorState::default();
+        add_rectangle(&mut state, "100", "100");
+        add_rectangle(&mut state, "100", "200");
+        state.multiply_factors("12");
+        assert_eq!(state.get_str_area(), "36");
+        state.set_selected(0, true);
+        state.multiply_factors("0,5");
+        assert_eq!(state.get_str_area(), "30");
+        state.multiply_factors("x");
+        assert_eq!(state.get_str_area(), "30");
+        state.undo();
+        assert_eq!(state.get_str_area(), "36");
+    }
+
+    #[test]
+    fn test_tolerance_range() {
+        let mut state = CalculatorState::default();
+        add_rectangle(&mut state, "100", "100");
+        assert_eq!(state.get_str_area_range(), None);
+        add_rectangle(&mut state, "100±10", "90..110");
+        assert_eq!(state.get_str_area(), "2");
+        let (min, max) = state.get_results()[1].get_range().unwrap();
+        assert!((min - 0.81).abs() < 1e-9 && (max - 1.21).abs() < 1e-9);
+        let (min, max) = state.area_range().unwrap();
+        assert!((min - 1.81).abs() < 1e-9 && (max - 2.21).abs() < 1e-9);
+        assert!(state.get_str_range(0).is_none());
+    }
+
+    #[test]
+    fn test_undo_edit() {
+        let mut state = CalculatorState::default();
+        add_rectangle(&mut state, "100", "100");
+        state.begin_edit(0);
+        state.form_state_from_result(0).unwrap()[1] =
+            crate::shapes::FormElement::InputField("", String::from("300"));
+        assert!(state.recalculate(0));
+        assert_eq!(state.get_str_area(), "3");
+        state.new_output_unit(crate::measure::AreaUnits::SM2);
+        state.undo();
+        assert_eq!(state.get_str_area(), "100");
+        assert_eq!(state.get_results().len(), 1);
+    }
+
+    #[test]
+    fn test_failed_edit() {
+        let mut state = CalculatorState::default();
+        add_rectangle(&mut state, "100", "100");
+        state.begin_edit(0);
+        state.form_state_from_result(0).unwrap()[1] =
+            crate::shapes::FormElement::InputField("", String::from("x"));
+        assert!(!state.recalculate(0));
+        assert_eq!(state.get_str_area(), "1");
+        state.cancel_edit();
+        assert!(state.recalculate(0));
+        assert_eq!(state.get_str_area(), "1");
+    }
+}
@@ -0,0 +1,253 @@
+use area_calculator::{literals, measure, number_format::NumberFormat, shapes};
+
+pub const FLAG: &str = "--cli";
+
+const USAGE: &str = "\
+Usage:
+    area_calculator --cli <shape> <dimension>... [options]
+    area_calculator --cli --list
+    area_calculator --batch < parts.json > results.json
+    area_calculator --serve [address]     (built with the server feature)
+
+Options:
+    --factor <k>       multiply the area by k (default 1)
+    --option <name>    enable a shape option, e.g. threaded (repeatable)
+    --input <unit>     input length units: mm, cm, dm, m (default mm)
+    --output <unit>    output area units: mm2, cm2, dm2, m2 (default dm2)
+    --summary          print the result line instead of the bare area
+    --steps            also print the formula and intermediate values
+    --list             list available shapes and their fields
+    --lang <code>      language of names and messages: en, ru, de, uk
+                       (default en, also for --batch and --serve)";
+
+#[derive(Debug, PartialEq)]
+struct Arguments {
+    shape: String,
+    dimensions: Vec<String>,
+    factor: String,
+    options: Vec<String>,
+    input_units: measure::LengthUnits,
+    output_units: measure::AreaUnits,
+    summary: bool,
+    steps: bool,
+}
+
+#[derive(Debug, PartialEq)]
+enum Command {
+    Calculate(Arguments),
+    List,
+    Help,
+}
+
+pub fn run(args: &[String]) -> i32 {
+    match parse_args(args) {
+        Ok(Command::Calculate(arguments)) => match calculate(&arguments) {
+            Ok(output) => {
+                println!("{}", output);
+                0
+            }
+            Err(err) => {
+                eprintln!("{}", literals::tr(err));
+                1
+            }
+        },
+        Ok(Command::List) => {
+            print!("{}", list_shapes());
+            0
+        }
+        Ok(Command::Help) => {
+            println!("{}", USAGE);
+            0
+        }
+        Err(err) => {
+            eprintln!("{}\n\n{}", err, USAGE);
+            2
+        }
+    }
+}
+
+fn parse_args(args: &[String]) -> Result<Command, String> {
+    let mut shape = None;
+    let mut dimensions = Vec::new();
+    let mut factor = String::new();
+    let mut options = Vec::new();
+    let mut input_units = measure::LengthUnits::MM;
+    let mut output_units = measure::AreaUnits::DM2;
+    let mut summary = false;
+    let mut steps = false;
+    let mut args = args.iter();
+    while let Some(arg) = args.next() {
+        let mut value = || {
+            args.next()
+                .cloned()
+                .ok_or_else(|| format!("Missing value for {}", arg))
+        };
+        match arg.as_str() {
+            "--help" | "-h" => return Ok(Command::Help),
+            "--list" => return Ok(Command::List),
+            "--factor" => factor = value()?,
+            "--option" => options.push(value()?),
+            "--input" => input_units = value()?.parse()?,
+            "--output" => output_units = value()?.parse()?,
+            "--summary" => summary = true,
+            "--steps" => steps = true,
+            _ if shape.is_none() => shape = Some(arg.clone()),
+            _ => dimensions.push(arg.clone()),
+        }
+    }
+    match shape {
+        Some(shape) => Ok(Command::Calculate(Arguments {
+            shape,
+            dimensions,
+            factor,
+            options,
+            input_units,
+            output_units,
+            summary,
+            steps,
+        })),
+        None => Err(String::from("Missing shape name")),
+    }
+}
+
+fn calculate(arguments: &Arguments) -> Result<String, &'static str> {
+    let mut shapes = shapes::get_shapes();
+    let shape =
+        shapes::find_shape(&mut shapes, &arguments.shape).ok_or(literals::messages::SHAPE_FAIL)?;
+    let dimensions: Vec<&str> = arguments.dimensions.iter().map(String::as_str).collect();
+    let options: Vec<&str> = arguments.options.iter().map(String::as_str).collect();
+    shape.fill_form(&dimensions, &arguments.factor, &options)?;
+    let format = NumberFormat::default();
+    let result = shape.calculate(
+        arguments.input_units.value(),
+        arguments.output_units.value(),
+        &format,
+    )?;
+    let mut output = if arguments.summary {
+        result.get_result().to_string()
+    } else {
+        result.get_area().to_string()
+    };
+    if arguments.steps {
+        for line in result.get_breakdown(arguments.input_units, arguments.output_units, &format) {
+            output.push('\n');
+            output.push_str(&line);
+        }
+    }
+    Ok(output)
+}
+
+fn list_shapes() -> String {
+    let mut output = String::new();
+    for shape in shapes::get_shapes().iter_mut() {
+        let key = shape.key();
+        let name = shape.name().to_string();
+        let fields: Vec<String> = shape
+            .form_state()
+            .iter()
+            .filter_map(|field| match field {
+                shapes::FormElement::InputField(label, _) => {
+                    Some(format!("<{}>", literals::tr(label)))
+                }
+                shapes::FormElement::OptionalField(label, _) => {
+                    Some(format!("[<{}>]", literals::tr(label)))
+                }
+                shapes::FormElement::CheckBox(label, _) => {
+                    Some(format!("[--option {}]", literals::tr(label)))
+                }
+                shapes::FormElement::Count(label, _, _) => {
+                    Some(format!("<{}>", literals::tr(label)))
+                }
+                shapes::FormElement::Choice(_, options, _) => {
+                    let options: Vec<&str> =
+                        options.iter().map(|option| literals::tr(option)).collect();
+                    Some(format!("[--option {}]", options.join("|")))
+                }
+                shapes::FormElement::Group(_, template, _) => {
+                    let entry: Vec<String> = template
+                        .iter()
+                        .filter_map(|field| match field {
+                            shapes::FormElement::InputField(label, _)
+                            | shapes::FormElement::OptionalField(label, _)
+                            | shapes::FormElement::Count(label, _, _) => {
+                                Some(format!("<{}>", literals::tr(label)))
+                            }
+                            _ => None,
+                        })
+                        .collect();
+                    Some(format!("[{}]...", entry.join(" ")))
+                }
+                _ => None,
+            })
+            .collect();
+        output.push_str(&format!("{} ({}): {}\n", key, name, fields.join(" ")));
+    }
+    output
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{calculate, parse_args, Command};
+    use area_calculator::measure;
+
+    fn args(line: &str) -> Vec<String> {
+        line.split_whitespace().map(String::from).collect()
+    }
+
+    fn calculate_line(line: &str) -> Result<String, &'static str> {
+        match parse_args(&args(line)) {
+            Ok(Command::Calculate(arguments)) => calculate(&arguments),
+            _ => panic!("not a calculation"),
+        }
+    }
+
+    #[test]
+    fn test_parse_args() {
+        let command = parse_args(&args(
+            "cylinder 10 20 --factor 2 --option threaded --input cm",
+        ));
+        match command {
+            Ok(Command::Calculate(arguments)) => {
+                assert_eq!(arguments.shape, "cylinder");
+                assert_eq!(arguments.dimensions, vec!["10", "20"]);
+                assert_eq!(arguments.factor, "2");
+                assert_eq!(arguments.options, vec!["threaded"]);
+                assert_eq!(arguments.input_units, measure::LengthUnits::SM);
+                assert_eq!(arguments.output_units, measure::AreaUnits::DM2);
+            }
+            _ => panic!("unexpected command"),
+        }
+    }
+
+    #[test]
+    fn test_parse_args_errors() {
+        assert!(parse_args(&args("--factor")).is_err());
+        assert!(parse_args(&args("circle 10 --output km2")).is_err());
+        assert!(parse_args(&args("--summary")).is_err());
+    }
+
+    #[test]
+    fn test_calculate() {
+        assert_eq!(calculate_line("rectangle 100 200"), Ok(String::from("2")));
+        assert_eq!(
+            calculate_line("rectangle 1 2 --input m --output m2 --factor 3"),
+            Ok(String::from("6"))
+        );
+        assert_eq!(
+            calculate_line("cuboid 1 1 1 --output mm2"),
+            Ok(String::from("6"))
+        );
+        let output = calculate_line("rectangle 100 200 --output mm2 --steps").unwrap();
+        let mut lines = output.lines();
+        assert_eq!(lines.next(), Some("20000"));
+        assert_eq!(lines.next(), Some("S = l·h·k"));
+    }
+
+    #[test]
+    fn test_calculate_errors() {
+        assert!(calculate_line("triangle 10").is_err());
+        assert!(calculate_line("rectangle 10").is_err());
+        assert!(calculate_line("rectangle 10 10 10").is_err());
+        assert!(calculate_line("circle 10 --option threaded").is_err());
+    }
+}
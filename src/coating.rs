@@ -0,0 +1,77 @@
+//! Paint and powder coating consumption.
+
+use crate::literals;
+
+#[derive(Copy, Clone, PartialEq, Debug)]
+/// Kind of coating, see [`Coating::coverage`].
+pub enum Coating {
+    /// Liquid paint, consumption in litres.
+    Paint,
+    /// Powder coating, consumption in kilograms.
+    Powder,
+}
+
+impl Coating {
+    /// Every coating, in the order of the selector.
+    pub const ALL: [Coating; 2] = [Coating::Paint, Coating::Powder];
+
+    /// Theoretical coverage in m²/L for paint or m²/kg for powder.
+    /// `thickness` is the dry film thickness in µm, `solids` is the volume
+    /// solids in % for paint and the specific gravity in g/cm³ for powder.
+    pub fn coverage(&self, thickness: f64, solids: f64) -> f64 {
+        match self {
+            Coating::Paint => 10. * solids / thickness,
+            Coating::Powder => 1000. / (thickness * solids),
+        }
+    }
+
+    /// Name in the current language.
+    pub fn name(&self) -> &str {
+        literals::tr(match self {
+            Coating::Paint => literals::PAINT,
+            Coating::Powder => literals::POWDER,
+        })
+    }
+
+    /// Unit of the consumption: litres or kilograms.
+    pub fn unit(&self) -> &str {
+        literals::tr(match self {
+            Coating::Paint => literals::LITRE,
+            Coating::Powder => literals::KG,
+        })
+    }
+
+    /// Label of the `solids` argument of [`Coating::coverage`].
+    pub fn solids_label(&self) -> &'static str {
+        literals::tr(match self {
+            Coating::Paint => literals::VOLUME_SOLIDS,
+            Coating::Powder => literals::SPECIFIC_GRAVITY,
+        })
+    }
+}
+
+/// Coating needed for `area` m² at `coverage` per unit and a transfer
+/// efficiency from 0 to 1.
+pub fn consumption(area: f64, coverage: f64, transfer_efficiency: f64) -> f64 {
+    area / (coverage * transfer_efficiency)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{consumption, Coating};
+
+    #[test]
+    fn test_paint() {
+        // 50 % volume solids at 40 µm covers 12.5 m²/L
+        let coverage = Coating::Paint.coverage(40., 50.);
+        assert!((coverage - 12.5).abs() < 1e-12);
+        assert!((consumption(25., coverage, 0.5) - 4.).abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_powder() {
+        // 60 µm of 1.6 g/cm³ powder weighs 96 g/m²
+        let coverage = Coating::Powder.coverage(60., 1.6);
+        assert!((consumption(1., coverage, 1.) - 0.096).abs() < 1e-12);
+    }
+}
@@ -0,0 +1,322 @@
+//! Arithmetic expressions for the formulas of custom shapes.
+//!
+//! Supported are numbers with a decimal dot, variables, `+ - * / ^`,
+//! parentheses, the constant `pi` and the functions `sqrt`, `abs`, `sin`,
+//! `cos`, `tan` (radians), `min`, `max` and `if(condition, then, else)`,
+//! where a condition is true when it is not zero.
+
+#[derive(Clone, Debug, PartialEq)]
+enum Node {
+    Number(f64),
+    Variable(String),
+    Negate(Box<Node>),
+    Binary(char, Box<Node>, Box<Node>),
+    Call(Function, Vec<Node>),
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+enum Function {
+    Sqrt,
+    Abs,
+    Sin,
+    Cos,
+    Tan,
+    Min,
+    Max,
+    If,
+}
+
+impl Function {
+    fn from_name(name: &str) -> Option<Function> {
+        Some(match name {
+            "sqrt" => Function::Sqrt,
+            "abs" => Function::Abs,
+            "sin" => Function::Sin,
+            "cos" => Function::Cos,
+            "tan" => Function::Tan,
+            "min" => Function::Min,
+            "max" => Function::Max,
+            "if" => Function::If,
+            _ => return None,
+        })
+    }
+
+    fn arity(&self) -> usize {
+        match self {
+            Function::Min | Function::Max => 2,
+            Function::If => 3,
+            _ => 1,
+        }
+    }
+}
+
+/// Parsed formula, evaluated with [`Expression::evaluate`].
+#[derive(Clone, Debug, PartialEq)]
+pub struct Expression {
+    root: Node,
+}
+
+impl Expression {
+    /// Parses a formula, the error describes what was expected and where.
+    pub fn parse(source: &str) -> Result<Expression, String> {
+        let mut parser = Parser {
+            chars: source.chars().collect(),
+            position: 0,
+        };
+        let root = parser.expression()?;
+        parser.skip_spaces();
+        match parser.peek() {
+            None => Ok(Expression { root }),
+            Some(letter) => Err(parser.error(&format!("unexpected `{}`", letter))),
+        }
+    }
+
+    /// Names of the variables used, `pi` excluded.
+    pub fn variables(&self) -> Vec<&str> {
+        let mut variables = Vec::new();
+        collect_variables(&self.root, &mut variables);
+        variables
+    }
+
+    /// Value for the given variable values, NaN when a variable is missing.
+    pub fn evaluate(&self, variables: &[(&str, f64)]) -> f64 {
+        evaluate(&self.root, variables)
+    }
+}
+
+fn collect_variables<'a>(node: &'a Node, variables: &mut Vec<&'a str>) {
+    match node {
+        Node::Number(_) => {}
+        Node::Variable(name) => {
+            if name != "pi" && !variables.contains(&name.as_str()) {
+                variables.push(name);
+            }
+        }
+        Node::Negate(node) => collect_variables(node, variables),
+        Node::Binary(_, left, right) => {
+            collect_variables(left, variables);
+            collect_variables(right, variables);
+        }
+        Node::Call(_, arguments) => {
+            for argument in arguments {
+                collect_variables(argument, variables);
+            }
+        }
+    }
+}
+
+fn evaluate(node: &Node, variables: &[(&str, f64)]) -> f64 {
+    match node {
+        Node::Number(value) => *value,
+        Node::Variable(name) if name == "pi" => std::f64::consts::PI,
+        Node::Variable(name) => variables
+            .iter()
+            .find(|(variable, _)| variable == name)
+            .map_or(f64::NAN, |(_, value)| *value),
+        Node::Negate(node) => -evaluate(node, variables),
+        Node::Binary(operator, left, right) => {
+            let (left, right) = (evaluate(left, variables), evaluate(right, variables));
+            match operator {
+                '+' => left + right,
+                '-' => left - right,
+                '*' => left * right,
+                '/' => left / right,
+                _ => left.powf(right),
+            }
+        }
+        Node::Call(function, arguments) => {
+            let value = |index: usize| evaluate(&arguments[index], variables);
+            match function {
+                Function::Sqrt => value(0).sqrt(),
+                Function::Abs => value(0).abs(),
+                Function::Sin => value(0).sin(),
+                Function::Cos => value(0).cos(),
+                Function::Tan => value(0).tan(),
+                Function::Min => value(0).min(value(1)),
+                Function::Max => value(0).max(value(1)),
+                Function::If => {
+                    if value(0) != 0. {
+                        value(1)
+                    } else {
+                        value(2)
+                    }
+                }
+            }
+        }
+    }
+}
+
+struct Parser {
+    chars: Vec<char>,
+    position: usize,
+}
+
+impl Parser {
+    fn error(&self, message: &str) -> String {
+        format!("{} at {}", message, self.position + 1)
+    }
+
+    fn peek(&self) -> Option<char> {
+        self.chars.get(self.position).copied()
+    }
+
+    fn skip_spaces(&mut self) {
+        while self.peek().is_some_and(char::is_whitespace) {
+            self.position += 1;
+        }
+    }
+
+    /// Consumes `letter` if it is the next one after spaces.
+    fn accept(&mut self, letter: char) -> bool {
+        self.skip_spaces();
+        if self.peek() == Some(letter) {
+            self.position += 1;
+            true
+        } else {
+            false
+        }
+    }
+
+    fn expect(&mut self, letter: char) -> Result<(), String> {
+        if self.accept(letter) {
+            Ok(())
+        } else {
+            Err(self.error(&format!("expected `{}`", letter)))
+        }
+    }
+
+    fn expression(&mut self) -> Result<Node, String> {
+        let mut node = self.term()?;
+        loop {
+            let operator = if self.accept('+') {
+                '+'
+            } else if self.accept('-') {
+                '-'
+            } else {
+                return Ok(node);
+            };
+            node = Node::Binary(operator, Box::new(node), Box::new(self.term()?));
+        }
+    }
+
+    fn term(&mut self) -> Result<Node, String> {
+        let mut node = self.unary()?;
+        loop {
+            let operator = if self.accept('*') {
+                '*'
+            } else if self.accept('/') {
+                '/'
+            } else {
+                return Ok(node);
+            };
+            node = Node::Binary(operator, Box::new(node), Box::new(self.unary()?));
+        }
+    }
+
+    fn unary(&mut self) -> Result<Node, String> {
+        if self.accept('-') {
+            return Ok(Node::Negate(Box::new(self.unary()?)));
+        }
+        let base = self.atom()?;
+        if self.accept('^') {
+            // Right associative and binds tighter than a sign: -a^2 = -(a^2)
+            return Ok(Node::Binary('^', Box::new(base), Box::new(self.unary()?)));
+        }
+        Ok(base)
+    }
+
+    fn atom(&mut self) -> Result<Node, String> {
+        self.skip_spaces();
+        match self.peek() {
+            Some('(') => {
+                self.position += 1;
+                let node = self.expression()?;
+                self.expect(')')?;
+                Ok(node)
+            }
+            Some(letter) if letter.is_ascii_digit() || letter == '.' => self.number(),
+            Some(letter) if letter.is_alphabetic() || letter == '_' => self.name(),
+            Some(letter) => Err(self.error(&format!("unexpected `{}`", letter))),
+            None => Err(self.error("unexpected end")),
+        }
+    }
+
+    fn number(&mut self) -> Result<Node, String> {
+        let start = self.position;
+        while self
+            .peek()
+            .is_some_and(|letter| letter.is_ascii_digit() || letter == '.')
+        {
+            self.position += 1;
+        }
+        let text: String = self.chars[start..self.position].iter().collect();
+        text.parse()
+            .map(Node::Number)
+            .map_err(|_| self.error(&format!("wrong number `{}`", text)))
+    }
+
+    fn name(&mut self) -> Result<Node, String> {
+        let start = self.position;
+        while self
+            .peek()
+            .is_some_and(|letter| letter.is_alphanumeric() || letter == '_')
+        {
+            self.position += 1;
+        }
+        let name: String = self.chars[start..self.position].iter().collect();
+        if !self.accept('(') {
+            return Ok(Node::Variable(name));
+        }
+        let function = Function::from_name(&name)
+            .ok_or_else(|| self.error(&format!("unknown function `{}`", name)))?;
+        let mut arguments = vec![self.expression()?];
+        while self.accept(',') {
+            arguments.push(self.expression()?);
+        }
+        self.expect(')')?;
+        if arguments.len() != function.arity() {
+            return Err(self.error(&format!("`{}` takes {} arguments", name, function.arity())));
+        }
+        Ok(Node::Call(function, arguments))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Expression;
+
+    fn value(source: &str, variables: &[(&str, f64)]) -> f64 {
+        Expression::parse(source).unwrap().evaluate(variables)
+    }
+
+    #[test]
+    fn test_evaluate() {
+        assert_eq!(value("1 + 2 * 3", &[]), 7.);
+        assert_eq!(value("(1 + 2) * 3", &[]), 9.);
+        assert_eq!(value("2 ^ 3 ^ 2", &[]), 512.);
+        assert_eq!(value("-a^2 + b", &[("a", 3.), ("b", 1.)]), -8.);
+        assert_eq!(value("10 / 4 - .5", &[]), 2.);
+        assert_eq!(
+            value("max(a, 2) * if(t, 2, 1)", &[("a", 1.), ("t", 1.)]),
+            4.
+        );
+        assert!((value("pi * d^2 / 4", &[("d", 2.)]) - std::f64::consts::PI).abs() < 1e-12);
+        assert!(value("a + c", &[("a", 1.)]).is_nan());
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        assert!(Expression::parse("1 +").is_err());
+        assert!(Expression::parse("(1 + 2").is_err());
+        assert!(Expression::parse("1 2").is_err());
+        assert!(Expression::parse("foo(1)").is_err());
+        assert!(Expression::parse("min(1)").is_err());
+        assert!(Expression::parse("1.2.3").is_err());
+    }
+
+    #[test]
+    fn test_variables() {
+        let expression = Expression::parse("pi * a * (b + a) + sqrt(c)").unwrap();
+        assert_eq!(expression.variables(), vec!["a", "b", "c"]);
+    }
+}
@@ -0,0 +1,115 @@
+//! Dimensions of ISO metric fasteners in mm: hex bolts (ISO 4014), hex nuts
+//! (ISO 4032) and plain washers (ISO 7089), coarse pitch threads.
+
+/// Dimensions of the bolt, nut and washer of one thread size.
+pub struct MetricSize {
+    /// Thread designation, e.g. `M8`.
+    pub name: &'static str,
+    /// Major diameter of the thread.
+    pub diameter: f64,
+    /// Coarse thread pitch.
+    pub pitch: f64,
+    /// Width across flats of the head and the nut.
+    pub width_across_flats: f64,
+    /// Height of the bolt head.
+    pub head_height: f64,
+    /// Height of the nut.
+    pub nut_height: f64,
+    /// Inner diameter of the washer.
+    pub washer_inner: f64,
+    /// Outer diameter of the washer.
+    pub washer_outer: f64,
+    /// Thickness of the washer.
+    pub washer_thickness: f64,
+}
+
+const fn size(
+    name: &'static str,
+    diameter: f64,
+    pitch: f64,
+    width_across_flats: f64,
+    head_height: f64,
+    nut_height: f64,
+    washer: [f64; 3],
+) -> MetricSize {
+    MetricSize {
+        name,
+        diameter,
+        pitch,
+        width_across_flats,
+        head_height,
+        nut_height,
+        washer_inner: washer[0],
+        washer_outer: washer[1],
+        washer_thickness: washer[2],
+    }
+}
+
+/// Standard sizes from M3 to M24.
+pub const SIZES: [MetricSize; 10] = [
+    size("M3", 3., 0.5, 5.5, 2., 2.4, [3.2, 7., 0.5]),
+    size("M4", 4., 0.7, 7., 2.8, 3.2, [4.3, 9., 0.8]),
+    size("M5", 5., 0.8, 8., 3.5, 4.7, [5.3, 10., 1.]),
+    size("M6", 6., 1., 10., 4., 5.2, [6.4, 12., 1.6]),
+    size("M8", 8., 1.25, 13., 5.3, 6.8, [8.4, 16., 1.6]),
+    size("M10", 10., 1.5, 16., 6.4, 8.4, [10.5, 20., 2.]),
+    size("M12", 12., 1.75, 18., 7.5, 10.8, [13., 24., 2.5]),
+    size("M16", 16., 2., 24., 10., 14.8, [17., 30., 3.]),
+    size("M20", 20., 2.5, 30., 12.5, 18., [21., 37., 3.]),
+    size("M24", 24., 3., 36., 15., 21.5, [25., 44., 4.]),
+];
+
+/// Size preselected in the fastener form.
+pub const DEFAULT: &str = "M10";
+
+/// Names of [`SIZES`] in the same order.
+pub const NAMES: [&str; SIZES.len()] = {
+    let mut names = [""; SIZES.len()];
+    let mut index = 0;
+    while index < SIZES.len() {
+        names[index] = SIZES[index].name;
+        index += 1;
+    }
+    names
+};
+
+/// Size by name, e.g. `"M10"`, ignoring case.
+pub fn find(name: &str) -> Option<&'static MetricSize> {
+    SIZES
+        .iter()
+        .find(|size| size.name.eq_ignore_ascii_case(name))
+}
+
+/// Index of [`DEFAULT`] in [`SIZES`].
+pub fn default_index() -> usize {
+    NAMES.iter().position(|name| *name == DEFAULT).unwrap_or(0)
+}
+
+/// Thread length of a hex bolt, the whole `length` for short bolts.
+pub fn thread_length(diameter: f64, length: f64) -> f64 {
+    let thread = if length <= 125. {
+        2. * diameter + 6.
+    } else if length <= 200. {
+        2. * diameter + 12.
+    } else {
+        2. * diameter + 25.
+    };
+    thread.min(length)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{default_index, find, thread_length, DEFAULT, NAMES};
+
+    #[test]
+    fn test_catalog() {
+        assert_eq!(NAMES[default_index()], DEFAULT);
+        let m10 = find("m10").unwrap();
+        assert_eq!(m10.pitch, 1.5);
+        assert_eq!(m10.width_across_flats, 16.);
+        assert!(find("M11").is_none());
+        assert_eq!(thread_length(10., 50.), 26.);
+        assert_eq!(thread_length(10., 20.), 20.);
+        assert_eq!(thread_length(10., 150.), 32.);
+    }
+}
@@ -0,0 +1,40 @@
+//! Surface area calculation engine behind the area calculator.
+//!
+//! The crate has no GUI dependencies: shapes are described by a form
+//! ([`shapes::FormElement`]) that is filled with text input, parsed and
+//! turned into a [`shapes::CalculationResult`].
+//!
+//! ```
+//! use area_calculator::{measure::AreaUnits, measure::LengthUnits, shapes};
+//! use area_calculator::number_format::NumberFormat;
+//!
+//! let mut shapes = shapes::get_shapes();
+//! let cylinder = shapes::find_shape(&mut shapes, "cylinder").unwrap();
+//! cylinder.fill_form(&["10", "20"], "2", &[]).unwrap();
+//! let result = cylinder
+//!     .calculate(
+//!         LengthUnits::MM.value(),
+//!         AreaUnits::MM2.value(),
+//!         &NumberFormat::default(),
+//!     )
+//!     .unwrap();
+//! assert!((result.get_area() - 400. * std::f64::consts::PI).abs() < 1e-9);
+//! ```
+
+#![warn(missing_docs)]
+
+mod calculator_state;
+pub mod coating;
+pub mod expression;
+pub mod fasteners;
+pub mod literals;
+pub mod material;
+pub mod measure;
+pub mod number_format;
+pub mod parser;
+pub mod plating;
+pub mod pricing;
+pub mod shapes;
+pub mod thread;
+
+pub use calculator_state::CalculatorState;
@@ -1,123 +1,176 @@
-pub mod messages;
-
-pub const STEP: f32 = 50.;
-
-pub const APP_TITLE: &str = if cfg!(feature = "lang_rus") {
-    "Калькулятор площади"
-} else {
-    "Area calculator"
-};
-
-pub const INPUT_UNITS: &str = if cfg!(feature = "lang_rus") {
-    "Единицы ввода"
-} else {
-    "Input units"
-};
-
-pub const OUTPUT_UNITS: &str = if cfg!(feature = "lang_rus") {
-    "Единицы вывода"
-} else {
-    "Output units"
-};
-
-pub const FACTOR: &str = if cfg!(feature = "lang_rus") {
-    "Коэффицент"
-} else {
-    "Factor"
-};
-
-pub const CALCULATE: &str = if cfg!(feature = "lang_rus") {
-    "Рассчитать"
-} else {
-    "Calculate"
-};
-
-pub const CLEAR: &str = if cfg!(feature = "lang_rus") {
-    "Очистить"
-} else {
-    "Clear"
-};
-
-pub const COPY: &str = if cfg!(feature = "lang_rus") {
-    "Скопировать"
-} else {
-    "Copy to buffer"
-};
-
-pub const TOTAL: &str = if cfg!(feature = "lang_rus") {
-    "Итого:"
-} else {
-    "Total:"
-};
+//! Keys of the interface texts, translated with [`tr`].
+//!
+//! Each key is documented with its English text from `assets/locales/en.ftl`.
 
-pub const EDIT: &str = if cfg!(feature = "lang_rus") {
-    "Редактировать"
-} else {
-    "Edit shape"
-};
-
-pub const CANCEL: &str = if cfg!(feature = "lang_rus") {
-    "Отмена"
-} else {
-    "Cancel"
-};
-
-pub const SAVE: &str = if cfg!(feature = "lang_rus") {
-    "Сохранить"
-} else {
-    "Save"
-};
-
-pub const MM: &str = if cfg!(feature = "lang_rus") {
-    "мм"
-} else {
-    "mm"
-};
-
-pub const SM: &str = if cfg!(feature = "lang_rus") {
-    "см"
-} else {
-    "sm"
-};
-
-pub const DM: &str = if cfg!(feature = "lang_rus") {
-    "дм"
-} else {
-    "dm"
-};
-
-pub const M: &str = if cfg!(feature = "lang_rus") {
-    "м"
-} else {
-    "m"
-};
-
-pub const MM2: &str = if cfg!(feature = "lang_rus") {
-    "мм²"
-} else {
-    "mm²"
-};
-
-pub const SM2: &str = if cfg!(feature = "lang_rus") {
-    "см²"
-} else {
-    "sm²"
-};
+mod catalog;
+pub mod messages;
 
-pub const DM2: &str = if cfg!(feature = "lang_rus") {
-    "дм²"
-} else {
-    "dm²"
-};
+pub use catalog::{language, load_translations, set_language, tr, verbatim, Language};
 
-pub const M2: &str = if cfg!(feature = "lang_rus") {
-    "м²"
-} else {
-    "m²"
-};
+/// Base size of the interface in points, not a key.
+pub const STEP: f32 = 50.;
 
-pub const PARSE_ERROR: &str = if cfg!(feature = "lang_rus") {
-    "Ввод некорректных данных"
-} else {
-    "Wrong input"
-};
+pub(crate) const LANGUAGE_NAME: &str = "language-name";
+pub(crate) const LANGUAGE_DECIMAL: &str = "language-decimal";
+/// "Area calculator"
+pub const APP_TITLE: &str = "app-title";
+/// "Input units"
+pub const INPUT_UNITS: &str = "input-units";
+/// "Output units"
+pub const OUTPUT_UNITS: &str = "output-units";
+/// "Factor"
+pub const FACTOR: &str = "factor";
+/// "Calculate"
+pub const CALCULATE: &str = "calculate";
+/// "Clear"
+pub const CLEAR: &str = "clear";
+/// "Copy to buffer"
+pub const COPY: &str = "copy";
+/// "Copy the list with labels and notes"
+pub const COPY_LIST: &str = "copy-list";
+/// "Total:"
+pub const TOTAL: &str = "total";
+/// "Edit shape"
+pub const EDIT: &str = "edit";
+/// "Cancel"
+pub const CANCEL: &str = "cancel";
+/// "Save"
+pub const SAVE: &str = "save";
+/// "mm"
+pub const MM: &str = "mm";
+/// "sm"
+pub const SM: &str = "sm";
+/// "dm"
+pub const DM: &str = "dm";
+/// "m"
+pub const M: &str = "m";
+/// "mm²"
+pub const MM2: &str = "mm2";
+/// "sm²"
+pub const SM2: &str = "sm2";
+/// "dm²"
+pub const DM2: &str = "dm2";
+/// "m²"
+pub const M2: &str = "m2";
+/// "Material"
+pub const MATERIAL: &str = "material";
+/// "Mass:"
+pub const MASS: &str = "mass";
+/// "kg"
+pub const KG: &str = "kg";
+/// "per item"
+pub const PER_ITEM: &str = "per-item";
+pub(crate) const STEEL: &str = "steel";
+pub(crate) const ALUMINIUM: &str = "aluminium";
+pub(crate) const BRASS: &str = "brass";
+pub(crate) const COPPER: &str = "copper";
+/// "Electroplating"
+pub const PLATING: &str = "plating";
+/// "Metal"
+pub const METAL: &str = "metal";
+/// "Current density, A/dm²"
+pub const CURRENT_DENSITY: &str = "current-density";
+/// "Coating thickness, µm"
+pub const THICKNESS: &str = "thickness";
+/// "Current efficiency, %"
+pub const EFFICIENCY: &str = "efficiency";
+/// "Current:"
+pub const CURRENT: &str = "current";
+/// "Time:"
+pub const TIME: &str = "time";
+/// "Metal consumption:"
+pub const METAL_CONSUMPTION: &str = "metal-consumption";
+/// "A"
+pub const AMPERE: &str = "ampere";
+/// "min"
+pub const MINUTE: &str = "minute";
+/// "g"
+pub const GRAM: &str = "gram";
+pub(crate) const ZINC: &str = "zinc";
+pub(crate) const NICKEL: &str = "nickel";
+pub(crate) const CHROMIUM: &str = "chromium";
+pub(crate) const TIN: &str = "tin";
+pub(crate) const SILVER: &str = "silver";
+/// "Coating consumption"
+pub const COATING: &str = "coating";
+/// "Coating"
+pub const COATING_TYPE: &str = "coating-type";
+pub(crate) const PAINT: &str = "paint";
+pub(crate) const POWDER: &str = "powder";
+/// "Coverage rate, m²/unit"
+pub const COVERAGE: &str = "coverage";
+/// "Dry film thickness, µm"
+pub const FILM_THICKNESS: &str = "film-thickness";
+pub(crate) const VOLUME_SOLIDS: &str = "volume-solids";
+pub(crate) const SPECIFIC_GRAVITY: &str = "specific-gravity";
+/// "Transfer efficiency, %"
+pub const TRANSFER_EFFICIENCY: &str = "transfer-efficiency";
+/// "Consumption:"
+pub const CONSUMPTION: &str = "consumption";
+pub(crate) const LITRE: &str = "litre";
+/// "Pricing"
+pub const PRICING: &str = "pricing";
+/// "Price per"
+pub const PRICE: &str = "price";
+/// "Minimum charge per item"
+pub const MIN_CHARGE: &str = "min-charge";
+/// "Setup fee"
+pub const SETUP_FEE: &str = "setup-fee";
+/// "Quote total:"
+pub const QUOTE_TOTAL: &str = "quote-total";
+/// "Group"
+pub const GROUP: &str = "group";
+pub(crate) const DEFAULT_GROUP: &str = "default-group";
+/// "Rename the group"
+pub const RENAME_GROUP: &str = "rename-group";
+/// "Delete the group, its results move to the first group"
+pub const DELETE_GROUP: &str = "delete-group";
+/// "Label"
+pub const LABEL: &str = "label";
+/// "Note"
+pub const NOTE: &str = "note";
+/// "Undo (Ctrl+Z)"
+pub const UNDO: &str = "undo";
+/// "Redo (Ctrl+Shift+Z)"
+pub const REDO: &str = "redo";
+/// "Duplicate"
+pub const DUPLICATE: &str = "duplicate";
+/// "Drag to move"
+pub const DRAG_TO_MOVE: &str = "drag-to-move";
+/// "Selected"
+pub const SELECTED: &str = "selected";
+/// "Set factor"
+pub const SET_FACTOR: &str = "set-factor";
+/// "Delete selected"
+pub const DELETE_SELECTED: &str = "delete-selected";
+/// "Multiply factor"
+pub const MULTIPLY_FACTOR: &str = "multiply-factor";
+/// "Selected results, or all when nothing is selected"
+pub const MULTIPLY_FACTOR_HINT: &str = "multiply-factor-hint";
+/// "Area min…max:"
+pub const AREA_RANGE: &str = "area-range";
+/// "Number format"
+pub const NUMBER_FORMAT: &str = "number-format";
+/// "Decimal places"
+pub const DECIMALS: &str = "decimals";
+/// "Significant digits"
+pub const SIGNIFICANT: &str = "significant";
+/// "Rounding"
+pub const ROUNDING: &str = "rounding";
+/// "Half up"
+pub const HALF_UP: &str = "half-up";
+/// "Half even"
+pub const HALF_EVEN: &str = "half-even";
+/// "Down (truncate)"
+pub const ROUND_DOWN: &str = "round-down";
+/// "Up"
+pub const ROUND_UP: &str = "round-up";
+/// "Thousands separator"
+pub const GROUPING: &str = "grouping";
+/// "None"
+pub const NO_GROUPING: &str = "no-grouping";
+/// "Decimal separator"
+pub const DECIMAL_SEPARATOR: &str = "decimal-separator";
+/// "Formula and working"
+pub const WORKING: &str = "working";
+pub(crate) const AREA: &str = "area";
@@ -0,0 +1,276 @@
+//! Runtime translation catalog: every literal is a key looked up with
+//! [`tr`] in the current [`Language`].
+//!
+//! Texts come from Fluent-style resource files (`key = text`, `#` comments,
+//! indented lines continue the previous text). The files shipped in
+//! `assets/locales` are embedded, English is the fallback for missing keys;
+//! every `<code>.ftl` file found by [`load_translations`] adds a language or
+//! replaces single embedded texts. The name of a language in the selector
+//! is its `language-name` text.
+
+use std::collections::{BTreeSet, HashMap};
+use std::path::Path;
+use std::sync::{OnceLock, RwLock};
+
+use super::{LANGUAGE_DECIMAL, LANGUAGE_NAME};
+
+/// A language of the catalog: an embedded one or one loaded from disk.
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub struct Language(usize);
+
+impl Language {
+    /// The fallback language, always present.
+    pub const ENGLISH: Language = Language(0);
+
+    /// Every language of the catalog, English first.
+    pub fn all() -> Vec<Language> {
+        let count = catalogs()
+            .read()
+            .map(|catalogs| catalogs.len())
+            .unwrap_or(1);
+        (0..count).map(Language).collect()
+    }
+
+    /// Name of the language in itself, for the language selector, or its
+    /// code when the file has no `language-name`.
+    pub fn name(&self) -> &'static str {
+        catalogs()
+            .read()
+            .ok()
+            .and_then(|catalogs| {
+                let (code, catalog) = catalogs.get(self.0)?;
+                Some(catalog.get(LANGUAGE_NAME).copied().unwrap_or(code))
+            })
+            .unwrap_or(ENGLISH)
+    }
+
+    /// Usual decimal separator of the language, `.` or `,`.
+    pub fn decimal(&self) -> &'static str {
+        translate(LANGUAGE_DECIMAL, *self)
+    }
+
+    /// ISO 639 code, also the name of the resource file.
+    pub fn code(&self) -> &'static str {
+        catalogs()
+            .read()
+            .ok()
+            .and_then(|catalogs| catalogs.get(self.0).map(|(code, _)| *code))
+            .unwrap_or(ENGLISH)
+    }
+
+    /// Language with the resource file `<code>.ftl`, if loaded.
+    pub fn from_code(code: &str) -> Option<Language> {
+        Language::all()
+            .into_iter()
+            .find(|language| language.code().eq_ignore_ascii_case(code))
+    }
+
+    /// Language from a locale name such as `ru_RU.UTF-8`, if loaded.
+    pub fn from_locale(locale: &str) -> Option<Language> {
+        let code = locale.split(['_', '.', '@']).next()?;
+        Language::from_code(code)
+    }
+}
+
+type Catalog = HashMap<&'static str, &'static str>;
+
+const ENGLISH: &str = "en";
+
+static LANGUAGE: RwLock<Language> = RwLock::new(Language::ENGLISH);
+/// Code and texts of every language, English first.
+static CATALOGS: OnceLock<RwLock<Vec<(&'static str, Catalog)>>> = OnceLock::new();
+/// Texts made by [`verbatim`].
+static VERBATIM: RwLock<BTreeSet<&'static str>> = RwLock::new(BTreeSet::new());
+
+/// Current interface language.
+pub fn language() -> Language {
+    LANGUAGE
+        .read()
+        .map(|language| *language)
+        .unwrap_or(Language::ENGLISH)
+}
+
+/// Changes the interface language, see also `CalculatorState::set_language`.
+pub fn set_language(language: Language) {
+    if let Ok(mut current) = LANGUAGE.write() {
+        *current = language;
+    }
+}
+
+/// Translation of `key` in the current language, falling back to English
+/// and then to the key itself.
+pub fn tr(key: &'static str) -> &'static str {
+    translate(key, language())
+}
+
+/// Static copy of a text written by the user, e.g. a custom shape label,
+/// that [`tr`] shows as it is even when it equals a key. Each distinct text
+/// is allocated once and never freed.
+pub fn verbatim(text: &str) -> &'static str {
+    let mut texts = VERBATIM.write().unwrap_or_else(|err| err.into_inner());
+    if let Some(text) = texts.get(text) {
+        return text;
+    }
+    let text: &'static str = Box::leak(text.into());
+    texts.insert(text);
+    text
+}
+
+/// Loads every `<code>.ftl` file in `dir`: a new code adds a language, a
+/// known one replaces the texts it contains. A missing `dir` is skipped.
+pub fn load_translations(dir: &Path) -> std::io::Result<()> {
+    let entries = match std::fs::read_dir(dir) {
+        Ok(entries) => entries,
+        Err(err) if err.kind() == std::io::ErrorKind::NotFound => return Ok(()),
+        Err(err) => return Err(err),
+    };
+    let mut files = Vec::new();
+    for entry in entries {
+        let path = entry?.path();
+        if path.extension().is_some_and(|extension| extension == "ftl") {
+            if let Some(code) = path.file_stem().and_then(|stem| stem.to_str()) {
+                files.push((code.to_string(), path.clone()));
+            }
+        }
+    }
+    files.sort();
+    for (code, path) in files {
+        let source = std::fs::read_to_string(&path)?;
+        // Loaded once at startup, the texts live as long as the program.
+        let texts = parse(Box::leak(source.into_boxed_str()));
+        let Ok(mut catalogs) = catalogs().write() else {
+            continue;
+        };
+        match catalogs.iter_mut().find(|(known, _)| *known == code) {
+            Some((_, catalog)) => catalog.extend(texts),
+            None => catalogs.push((Box::leak(code.into_boxed_str()), texts)),
+        }
+    }
+    Ok(())
+}
+
+fn catalogs() -> &'static RwLock<Vec<(&'static str, Catalog)>> {
+    CATALOGS.get_or_init(|| {
+        RwLock::new(vec![
+            (ENGLISH, parse(include_str!("../../assets/locales/en.ftl"))),
+            ("ru", parse(include_str!("../../assets/locales/ru.ftl"))),
+            ("de", parse(include_str!("../../assets/locales/de.ftl"))),
+            ("uk", parse(include_str!("../../assets/locales/uk.ftl"))),
+        ])
+    })
+}
+
+fn translate(key: &'static str, language: Language) -> &'static str {
+    // Compared by address: a key with the same text is still translated.
+    let is_verbatim = VERBATIM
+        .read()
+        .is_ok_and(|texts| texts.get(key).is_some_and(|text| std::ptr::eq(*text, key)));
+    if is_verbatim {
+        return key;
+    }
+    let Ok(catalogs) = catalogs().read() else {
+        return key;
+    };
+    catalogs
+        .get(language.0)
+        .and_then(|(_, catalog)| catalog.get(key))
+        .or_else(|| catalogs.first().and_then(|(_, catalog)| catalog.get(key)))
+        .copied()
+        .unwrap_or(key)
+}
+
+fn parse(source: &'static str) -> Catalog {
+    let mut catalog = Catalog::new();
+    let mut last_key = None;
+    for line in source.lines() {
+        if line.trim().is_empty() || line.trim_start().starts_with('#') {
+            continue;
+        }
+        if line.starts_with(char::is_whitespace) {
+            // Continuation of a multiline text
+            if let Some(text) = last_key.and_then(|key| catalog.get_mut(key)) {
+                *text = Box::leak(format!("{}\n{}", text, line.trim()).into_boxed_str());
+            }
+            continue;
+        }
+        if let Some((key, text)) = line.split_once('=') {
+            let key = key.trim();
+            catalog.insert(key, text.trim());
+            last_key = Some(key);
+        }
+    }
+    catalog
+}
+
+#[cfg(test)]
+mod tests {
+    use std::path::Path;
+
+    use super::{load_translations, parse, translate, verbatim, Language};
+    use crate::literals;
+
+    fn locales() -> &'static Path {
+        Path::new(concat!(env!("CARGO_MANIFEST_DIR"), "/assets/locales"))
+    }
+
+    fn language(code: &str) -> Language {
+        load_translations(locales()).unwrap();
+        Language::from_code(code).unwrap()
+    }
+
+    #[test]
+    fn test_translate() {
+        assert_eq!(translate(literals::FACTOR, Language::ENGLISH), "Factor");
+        assert_eq!(translate(literals::FACTOR, language("ru")), "Коэффицент");
+        assert_eq!(translate(literals::FACTOR, language("de")), "Faktor");
+        assert_eq!(translate("no-such-key", language("ru")), "no-such-key");
+        assert_eq!(language("uk").name(), "Українська");
+        assert_eq!(Language::ENGLISH.decimal(), ".");
+    }
+
+    #[test]
+    fn test_embedded() {
+        let russian = Language::from_code("ru").unwrap();
+        assert_eq!(translate(literals::FACTOR, russian), "Коэффицент");
+        assert_eq!(russian.decimal(), ",");
+        assert!(Language::from_code("uk").is_some());
+        assert!(Language::from_code("de").is_some());
+    }
+
+    #[test]
+    fn test_verbatim() {
+        let factor = verbatim(literals::FACTOR);
+        assert_eq!(translate(factor, Language::ENGLISH), "factor");
+        assert!(std::ptr::eq(verbatim("factor"), factor));
+        assert_eq!(translate(literals::FACTOR, Language::ENGLISH), "Factor");
+    }
+
+    #[test]
+    fn test_complete() {
+        let english = parse(include_str!("../../assets/locales/en.ftl"));
+        for entry in std::fs::read_dir(locales()).unwrap() {
+            let path = entry.unwrap().path();
+            let source = std::fs::read_to_string(&path).unwrap();
+            let catalog = parse(Box::leak(source.into_boxed_str()));
+            for key in english.keys() {
+                assert!(catalog.contains_key(key), "{} in {}", key, path.display());
+            }
+        }
+    }
+
+    #[test]
+    fn test_parse() {
+        let catalog = parse("# comment\n\nkey = Text = 1\nlong = First\n    second\n");
+        assert_eq!(catalog["key"], "Text = 1");
+        assert_eq!(catalog["long"], "First\nsecond");
+    }
+
+    #[test]
+    fn test_from_locale() {
+        let russian = language("ru");
+        assert_eq!(Language::from_locale("ru_RU.UTF-8"), Some(russian));
+        assert_eq!(Language::from_locale("uk_UA"), Some(language("uk")));
+        assert_eq!(Language::from_locale("en"), Some(Language::ENGLISH));
+        assert_eq!(Language::from_locale("C"), None);
+    }
+}
@@ -1,59 +1,34 @@
-pub const BUFFER_ERROR: &str = if cfg!(feature = "lang_rus") {
-    "Буфер обмена не доступен"
-} else {
-    "System clipboard unavailable"
-};
-
-pub const BUFFER_COPIED: &str = if cfg!(feature = "lang_rus") {
-    "Скопированно в буфер обмена"
-} else {
-    "Copied to clipboard"
-};
-
-pub const BUFFER_FAIL: &str = if cfg!(feature = "lang_rus") {
-    "Не удалось скопировать в буфер обмена"
-} else {
-    "Copy to clipboard failed"
-};
-
-pub const CALCULATION_ERR: &str = if cfg!(feature = "lang_rus") {
-    "Ошибка вычисления"
-} else {
-    "Calculation error"
-};
-
-pub const INPUT_WRONG: &str = if cfg!(feature = "lang_rus") {
-    "Некорректный ввод"
-} else {
-    "Incorrect input"
-};
-
-pub const PARSE_WRONG: &str = if cfg!(feature = "lang_rus") {
-    "Ошибка преобразования в число"
-} else {
-    "Error converting to a digit"
-};
-
-pub const WRONG_FIELD: &str = if cfg!(feature = "lang_rus") {
-    "Ошибка доступа у полю ввода"
-} else {
-    "Error accessing to the input firld"
-};
-
-pub const VIEW_FAIL: &str = if cfg!(feature = "lang_rus") {
-    "Ошибка отображения результата"
-} else {
-    "Result display error"
-};
-
-pub const SHAPE_FAIL: &str = if cfg!(feature = "lang_rus") {
-    "Неизвестная фигура"
-} else {
-    "Unknown shape"
-};
-
-pub const WRONG_BUSHING: &str = if cfg!(feature = "lang_rus") {
-    "Внутренний диаметр должен быть меньше внешнего"
-} else {
-    "The inner diameter must be smaller than the outer"
-};
+//! Keys of the status and error messages, also the errors of the calculations.
+
+/// "System clipboard unavailable"
+pub const BUFFER_ERROR: &str = "buffer-error";
+/// "Copied to clipboard"
+pub const BUFFER_COPIED: &str = "buffer-copied";
+/// "Copy to clipboard failed"
+pub const BUFFER_FAIL: &str = "buffer-fail";
+/// "Calculation error"
+pub const CALCULATION_ERR: &str = "calculation-err";
+/// "Incorrect input"
+pub const INPUT_WRONG: &str = "input-wrong";
+/// "Error converting to a digit"
+pub const PARSE_WRONG: &str = "parse-wrong";
+/// "Error accessing to the input firld"
+pub const WRONG_FIELD: &str = "wrong-field";
+/// "Unknown shape"
+pub const SHAPE_FAIL: &str = "shape-fail";
+/// "The inner diameter must be smaller than the outer"
+pub const WRONG_BUSHING: &str = "wrong-bushing";
+/// "Wrong number of dimensions"
+pub const WRONG_DIMENSIONS: &str = "wrong-dimensions";
+/// "Unknown option"
+pub const UNKNOWN_OPTION: &str = "unknown-option";
+/// "The option matches several choices, type more of it"
+pub const AMBIGUOUS_OPTION: &str = "ambiguous-option";
+/// "Unknown unit"
+pub const UNKNOWN_UNIT: &str = "unknown-unit";
+/// "The thread pitch is too large for the diameter"
+pub const WRONG_PITCH: &str = "wrong-pitch";
+/// "Enter the length of the bolt"
+pub const MISSING_LENGTH: &str = "missing-length";
+/// "The number could be grouped or decimal, add the decimal part"
+pub const AMBIGUOUS_NUMBER: &str = "ambiguous-number";
@@ -5,12 +5,59 @@ use std::sync::Arc;
 use eframe::egui;
 use egui::IconData;
 
+use area_calculator::{literals, shapes};
+
+#[cfg(not(target_arch = "wasm32"))]
+mod batch;
 mod calculator;
-pub mod literals;
-mod shapes;
+#[cfg(not(target_arch = "wasm32"))]
+mod cli;
+#[cfg(feature = "server")]
+mod server;
+
+#[cfg(not(target_arch = "wasm32"))]
+const LANG_FLAG: &str = "--lang";
 
 #[cfg(not(target_arch = "wasm32"))]
 fn main() -> Result<(), eframe::Error> {
+    let mut args: Vec<String> = std::env::args().skip(1).collect();
+    if command_line_mode(&args) {
+        attach_console();
+    }
+    if let Some(dir) = translations_dir() {
+        if let Err(err) = literals::load_translations(&dir) {
+            eprintln!("{}: {}", dir.display(), err);
+        }
+    }
+    if let Some(path) = custom_shapes_file() {
+        if let Err(err) = shapes::load_custom_shapes(&path) {
+            eprintln!("{}: {}", path.display(), err);
+        }
+    }
+    let language = match take_language(&mut args) {
+        Ok(language) => language,
+        Err(err) => {
+            eprintln!("{}", err);
+            std::process::exit(2);
+        }
+    };
+    // Machine output stays in the default language unless asked otherwise.
+    if let Some(language) = language {
+        literals::set_language(language);
+    }
+    if args.first().is_some_and(|arg| arg == cli::FLAG) {
+        std::process::exit(cli::run(&args[1..]));
+    }
+    if args.first().is_some_and(|arg| arg == batch::FLAG) {
+        std::process::exit(batch::run());
+    }
+    #[cfg(feature = "server")]
+    if args.first().is_some_and(|arg| arg == server::FLAG) {
+        std::process::exit(server::run(args.get(1).map(String::as_str)));
+    }
+    if let Some(language) = language.or_else(system_language) {
+        literals::set_language(language);
+    }
     let icon = include_bytes!("../assets/icon.rgb").to_vec();
     let window_size = egui::ViewportBuilder {
         min_inner_size: Some(egui::vec2(literals::STEP * 9.6, literals::STEP * 8.)),
@@ -27,12 +74,92 @@ fn main() -> Result<(), eframe::Error> {
         ..Default::default()
     };
     eframe::run_native(
-        literals::APP_TITLE,
+        literals::tr(literals::APP_TITLE),
         options,
         Box::new(|_cc| Box::<calculator::Calculator>::default()),
     )
 }
 
+/// Directory with extra or overriding translation files:
+/// `AREA_CALCULATOR_LOCALES` or `locales` next to the executable.
+#[cfg(not(target_arch = "wasm32"))]
+fn translations_dir() -> Option<std::path::PathBuf> {
+    std::env::var_os("AREA_CALCULATOR_LOCALES")
+        .map(std::path::PathBuf::from)
+        .or_else(|| {
+            let exe = std::env::current_exe().ok()?;
+            Some(exe.parent()?.join("locales"))
+        })
+}
+
+/// File with custom shape definitions: `AREA_CALCULATOR_SHAPES` or
+/// `shapes.json` next to the executable.
+#[cfg(not(target_arch = "wasm32"))]
+fn custom_shapes_file() -> Option<std::path::PathBuf> {
+    std::env::var_os("AREA_CALCULATOR_SHAPES")
+        .map(std::path::PathBuf::from)
+        .or_else(|| {
+            let exe = std::env::current_exe().ok()?;
+            Some(exe.parent()?.join("shapes.json"))
+        })
+}
+
+/// Removes `--lang <code>` from the arguments and returns its language.
+#[cfg(not(target_arch = "wasm32"))]
+fn take_language(args: &mut Vec<String>) -> Result<Option<literals::Language>, String> {
+    let Some(index) = args.iter().position(|arg| arg == LANG_FLAG) else {
+        return Ok(None);
+    };
+    args.remove(index);
+    if index >= args.len() {
+        return Err(format!("Missing value for {}", LANG_FLAG));
+    }
+    let code = args.remove(index);
+    literals::Language::from_code(&code)
+        .map(Some)
+        .ok_or_else(|| format!("Unknown language: {}", code))
+}
+
+/// Whether the arguments ask for a mode writing to the terminal.
+#[cfg(not(target_arch = "wasm32"))]
+fn command_line_mode(args: &[String]) -> bool {
+    #[cfg(feature = "server")]
+    let flags = [cli::FLAG, batch::FLAG, server::FLAG];
+    #[cfg(not(feature = "server"))]
+    let flags = [cli::FLAG, batch::FLAG];
+    args.iter().any(|arg| flags.contains(&arg.as_str()))
+}
+
+/// Release builds for Windows are GUI programs without a console, so the
+/// command line modes write to the console of the parent process, e.g.
+/// `cmd.exe`. Nothing is shown when started without one.
+#[cfg(all(windows, not(debug_assertions)))]
+fn attach_console() {
+    const ATTACH_PARENT_PROCESS: u32 = u32::MAX;
+    #[link(name = "kernel32")]
+    extern "system" {
+        fn AttachConsole(process_id: u32) -> i32;
+    }
+    // SAFETY: takes no pointers, failure only leaves the process without a
+    // console.
+    unsafe {
+        AttachConsole(ATTACH_PARENT_PROCESS);
+    }
+}
+
+#[cfg(all(not(target_arch = "wasm32"), not(all(windows, not(debug_assertions)))))]
+fn attach_console() {}
+
+/// Interface language from the locale environment variables.
+#[cfg(not(target_arch = "wasm32"))]
+fn system_language() -> Option<literals::Language> {
+    ["LC_ALL", "LC_MESSAGES", "LANG"]
+        .iter()
+        .filter_map(|var| std::env::var(var).ok())
+        .find(|locale| !locale.is_empty())
+        .and_then(|locale| literals::Language::from_locale(&locale))
+}
+
 #[cfg(target_arch = "wasm32")]
 fn main() {
     eframe::WebLogger::init(log::LevelFilter::Debug).ok();
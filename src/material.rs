@@ -0,0 +1,73 @@
+//! Materials for the mass calculation.
+
+use crate::literals;
+
+#[derive(Copy, Clone, PartialEq, Debug)]
+/// Material of a solid shape, see [`Material::density`].
+pub enum Material {
+    /// Carbon steel, 7.85 g/cm³.
+    Steel,
+    /// Aluminium, 2.70 g/cm³.
+    Aluminium,
+    /// Brass, 8.50 g/cm³.
+    Brass,
+    /// Copper, 8.96 g/cm³.
+    Copper,
+}
+
+impl Material {
+    /// Every material, in the order of the selector.
+    pub const ALL: [Material; 4] = [
+        Material::Steel,
+        Material::Aluminium,
+        Material::Brass,
+        Material::Copper,
+    ];
+
+    /// Density in kg/mm³.
+    pub fn density(&self) -> f64 {
+        let grams_per_cm3 = match self {
+            Material::Steel => 7.85,
+            Material::Aluminium => 2.70,
+            Material::Brass => 8.50,
+            Material::Copper => 8.96,
+        };
+        grams_per_cm3 * 1e-6
+    }
+
+    /// Name in the current language.
+    pub fn name(&self) -> &str {
+        literals::tr(match self {
+            Material::Steel => literals::STEEL,
+            Material::Aluminium => literals::ALUMINIUM,
+            Material::Brass => literals::BRASS,
+            Material::Copper => literals::COPPER,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Material;
+    use crate::number_format::NumberFormat;
+    use crate::shapes;
+
+    #[test]
+    fn test_steel_cube() {
+        let mut shapes = shapes::get_shapes();
+        let cuboid = shapes::find_shape(&mut shapes, "cuboid").unwrap();
+        cuboid.fill_form(&["10", "10", "10"], "2", &[]).unwrap();
+        let result = cuboid.calculate(1., 1., &NumberFormat::default()).unwrap();
+        let mass = result.get_mass(Material::Steel.density()).unwrap();
+        assert!((mass - 0.0157).abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_flat_shape() {
+        let mut shapes = shapes::get_shapes();
+        let circle = shapes::find_shape(&mut shapes, "circle").unwrap();
+        circle.fill_form(&["10"], "", &[]).unwrap();
+        let result = circle.calculate(1., 1., &NumberFormat::default()).unwrap();
+        assert_eq!(result.get_mass(Material::Steel.density()), None);
+    }
+}
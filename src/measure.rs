@@ -0,0 +1,104 @@
+//! Length and area units. Values are conversion factors to mm and mm².
+
+use std::str::FromStr;
+
+use crate::literals;
+use crate::literals::messages;
+
+#[derive(Copy, Clone, PartialEq, Debug)]
+/// Units of the input dimensions.
+pub enum LengthUnits {
+    /// Millimetres.
+    MM,
+    /// Centimetres.
+    SM,
+    /// Decimetres.
+    DM,
+    /// Metres.
+    M,
+}
+
+#[derive(Copy, Clone, PartialEq, Debug)]
+/// Units of the calculated areas.
+pub enum AreaUnits {
+    /// Square millimetres.
+    MM2,
+    /// Square centimetres.
+    SM2,
+    /// Square decimetres.
+    DM2,
+    /// Square metres.
+    M2,
+}
+
+
+impl LengthUnits {
+    /// Length of the unit in mm.
+    pub fn value(&self) -> f64 {
+        match self {
+            LengthUnits::MM => 1.,
+            LengthUnits::SM => 10.,
+            LengthUnits::DM => 100.,
+            LengthUnits::M => 1000.
+        }
+    }
+
+    /// Unit symbol in the current language.
+    pub fn name(&self) -> &str{
+        literals::tr(match self {
+            LengthUnits::MM => literals::MM,
+            LengthUnits::SM => literals::SM,
+            LengthUnits::DM => literals::DM,
+            LengthUnits::M => literals::M
+        })
+    }
+}
+
+impl AreaUnits {
+    /// Area of the unit in mm².
+    pub fn value(&self) -> f64 {
+        match self {
+            AreaUnits::MM2 => 1.,
+            AreaUnits::SM2 => 100.,
+            AreaUnits::DM2 => 10000.,
+            AreaUnits::M2 => 1000000.
+        }
+    }
+    /// Unit symbol in the current language.
+    pub fn name(&self) -> &str {
+        literals::tr(match self {
+            AreaUnits::MM2 => literals::MM2,
+            AreaUnits::SM2 => literals::SM2,
+            AreaUnits::DM2 => literals::DM2,
+            AreaUnits::M2 => literals::M2
+        })
+    }
+}
+
+impl FromStr for LengthUnits {
+    type Err = &'static str;
+
+    fn from_str(unit: &str) -> Result<Self, Self::Err> {
+        match unit.to_lowercase().as_str() {
+            "mm" => Ok(LengthUnits::MM),
+            "cm" | "sm" => Ok(LengthUnits::SM),
+            "dm" => Ok(LengthUnits::DM),
+            "m" => Ok(LengthUnits::M),
+            _ => Err(messages::UNKNOWN_UNIT),
+        }
+    }
+}
+
+impl FromStr for AreaUnits {
+    type Err = &'static str;
+
+    fn from_str(unit: &str) -> Result<Self, Self::Err> {
+        match unit.to_lowercase().trim_end_matches(['2', '²']) {
+            "mm" => Ok(AreaUnits::MM2),
+            "cm" | "sm" => Ok(AreaUnits::SM2),
+            "dm" => Ok(AreaUnits::DM2),
+            "m" => Ok(AreaUnits::M2),
+            _ => Err(messages::UNKNOWN_UNIT),
+        }
+    }
+}
@@ -0,0 +1,354 @@
+//! Formatting of calculated numbers for display.
+//!
+//! Rounding works on the shortest decimal representation of a value, so
+//! `0.285` rounded to two decimals is `0.29` and not `0.28`. Decimal and
+//! grouping separators are the user's number locale, independent of the
+//! interface language.
+//!
+//! The format is passed by the caller: the calculator keeps the one chosen
+//! by the user, [`NumberFormat::default`] is language independent and used
+//! for machine readable output.
+
+use crate::literals::Language;
+
+/// How many digits are kept.
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub enum Precision {
+    /// Fixed number of digits after the decimal point.
+    Decimals(usize),
+    /// Number of significant digits, trailing zeros are dropped. Digits
+    /// of the integer part are always kept, so only the fraction is rounded.
+    Significant(usize),
+}
+
+#[derive(Clone, Copy, PartialEq, Debug)]
+/// How the last kept digit is rounded.
+pub enum Rounding {
+    /// Ties away from zero.
+    HalfUp,
+    /// Ties to the even digit.
+    HalfEven,
+    /// Towards zero (truncation).
+    Down,
+    /// Away from zero.
+    Up,
+}
+
+/// Separator between thousands of the integer part.
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub enum Grouping {
+    /// `1234567`
+    None,
+    /// `1 234 567`
+    Space,
+    /// `1'234'567`
+    Apostrophe,
+    /// `1,234,567`
+    Comma,
+    /// `1.234.567`
+    Dot,
+}
+
+#[derive(Clone, Copy, PartialEq, Debug)]
+/// Separator of the fractional part.
+pub enum DecimalSeparator {
+    /// `2.5`
+    Dot,
+    /// `2,5`
+    Comma,
+}
+
+#[derive(Clone, Copy, PartialEq, Debug)]
+/// How results and totals are written, see [`NumberFormat::format`].
+pub struct NumberFormat {
+    /// Digits kept.
+    pub precision: Precision,
+    /// Rounding of the last kept digit.
+    pub rounding: Rounding,
+    /// Separator between thousands.
+    pub grouping: Grouping,
+    /// Separator of the fractional part, also accepted in the input.
+    pub decimal: DecimalSeparator,
+}
+
+impl Default for NumberFormat {
+    fn default() -> Self {
+        DEFAULT
+    }
+}
+
+const DEFAULT: NumberFormat = NumberFormat {
+    precision: Precision::Significant(6),
+    rounding: Rounding::HalfUp,
+    grouping: Grouping::None,
+    decimal: DecimalSeparator::Dot,
+};
+
+/// Significant digits of [`exact`], enough for any typed dimension while
+/// hiding the binary noise of unit conversions.
+const EXACT_DIGITS: usize = 12;
+
+/// Formats a calculated `value` with `format`.
+pub fn number(value: f64, format: &NumberFormat) -> String {
+    format.format(value)
+}
+
+/// Formats an entered dimension or factor with the separators of `format`
+/// but without its rounding, so `1234567` stays `1234567`.
+pub fn exact(value: f64, format: &NumberFormat) -> String {
+    NumberFormat {
+        precision: Precision::Significant(EXACT_DIGITS),
+        rounding: Rounding::HalfUp,
+        ..*format
+    }
+    .format(value)
+}
+
+/// Formats `value` with `decimals` digits after the separator, e.g. for
+/// prices and masses.
+pub fn fixed(value: f64, decimals: usize, format: &NumberFormat) -> String {
+    NumberFormat {
+        precision: Precision::Decimals(decimals),
+        rounding: Rounding::HalfUp,
+        ..*format
+    }
+    .format(value)
+}
+
+/// `value` in full precision as it would be typed into an input field.
+pub fn input_text(value: f64, format: &NumberFormat) -> String {
+    value
+        .to_string()
+        .replace('.', &format.decimal.char().to_string())
+}
+
+impl Rounding {
+    /// Every rounding mode, in the order of the selector.
+    pub const ALL: [Rounding; 4] = [
+        Rounding::HalfUp,
+        Rounding::HalfEven,
+        Rounding::Down,
+        Rounding::Up,
+    ];
+}
+
+impl Grouping {
+    /// Every grouping, in the order of the selector.
+    pub const ALL: [Grouping; 5] = [
+        Grouping::None,
+        Grouping::Space,
+        Grouping::Apostrophe,
+        Grouping::Comma,
+        Grouping::Dot,
+    ];
+
+    /// Separator character, `None` without grouping.
+    pub fn separator(&self) -> Option<char> {
+        match self {
+            Grouping::None => None,
+            Grouping::Space => Some(' '),
+            Grouping::Apostrophe => Some('\''),
+            Grouping::Comma => Some(','),
+            Grouping::Dot => Some('.'),
+        }
+    }
+}
+
+impl DecimalSeparator {
+    /// Both separators, in the order of the selector.
+    pub const ALL: [DecimalSeparator; 2] = [DecimalSeparator::Dot, DecimalSeparator::Comma];
+
+    /// Separator character.
+    pub fn char(&self) -> char {
+        match self {
+            DecimalSeparator::Dot => '.',
+            DecimalSeparator::Comma => ',',
+        }
+    }
+}
+
+impl NumberFormat {
+    /// Default format with the usual decimal separator of `language`.
+    pub fn for_language(language: Language) -> NumberFormat {
+        let decimal = match language.decimal() {
+            "," => DecimalSeparator::Comma,
+            _ => DecimalSeparator::Dot,
+        };
+        NumberFormat { decimal, ..DEFAULT }
+    }
+
+    /// Grouping separator, `None` when it is the same as the decimal one.
+    pub fn grouping_separator(&self) -> Option<char> {
+        self.grouping
+            .separator()
+            .filter(|separator| *separator != self.decimal.char())
+    }
+
+    /// Rounds `value` and writes it with the separators of the format.
+    pub fn format(&self, value: f64) -> String {
+        if !value.is_finite() {
+            return value.to_string();
+        }
+        // "d.ddde-x": the shortest digits that read back as `value`.
+        let scientific = format!("{:e}", value.abs());
+        let (mantissa, exponent) = scientific.split_once('e').unwrap_or((&scientific, "0"));
+        let mut exponent: i32 = exponent.parse().unwrap_or(0);
+        let mut digits: Vec<u8> = mantissa
+            .bytes()
+            .filter(u8::is_ascii_digit)
+            .map(|digit| digit - b'0')
+            .collect();
+        let (keep, decimals) = match self.precision {
+            Precision::Decimals(decimals) => (exponent + 1 + decimals as i32, decimals as i32),
+            Precision::Significant(significant) => {
+                let significant = (significant.max(1) as i32).max(exponent + 1);
+                (significant, significant - exponent - 1)
+            }
+        };
+        if keep < 1 {
+            let shift = 1 - keep;
+            digits.splice(0..0, std::iter::repeat(0).take(shift as usize));
+            exponent += shift;
+        }
+        let keep = keep.max(1) as usize;
+        digits.resize(digits.len().max(keep), 0);
+        let rest = digits.split_off(keep);
+        if self.round_up(&digits, &rest) && increment(&mut digits) {
+            exponent += 1;
+        }
+        let mut output = String::new();
+        if value.is_sign_negative() && digits.iter().any(|digit| *digit != 0) {
+            output.push('-');
+        }
+        let integer_len = exponent + 1;
+        let digit = |position: i32| -> char {
+            let position = position as usize;
+            char::from(b'0' + digits.get(position).copied().unwrap_or(0))
+        };
+        if integer_len <= 0 {
+            output.push('0');
+        } else {
+            for position in 0..integer_len {
+                let left = integer_len - position;
+                if position > 0 && left % 3 == 0 {
+                    if let Some(separator) = self.grouping_separator() {
+                        output.push(separator);
+                    }
+                }
+                output.push(digit(position));
+            }
+        }
+        let decimals = decimals.max(0);
+        let mut fraction: String = (0..decimals)
+            .map(|position| {
+                let position = integer_len + position;
+                if position < 0 {
+                    '0'
+                } else {
+                    digit(position)
+                }
+            })
+            .collect();
+        if let Precision::Significant(_) = self.precision {
+            fraction.truncate(fraction.trim_end_matches('0').len());
+        }
+        if !fraction.is_empty() {
+            output.push(self.decimal.char());
+            output.push_str(&fraction);
+        }
+        output
+    }
+
+    fn round_up(&self, kept: &[u8], rest: &[u8]) -> bool {
+        let first = rest.first().copied().unwrap_or(0);
+        let tail = rest.iter().skip(1).any(|digit| *digit != 0);
+        match self.rounding {
+            Rounding::Down => false,
+            Rounding::Up => first != 0 || tail,
+            Rounding::HalfUp => first >= 5,
+            Rounding::HalfEven => {
+                let odd = kept.last().is_some_and(|digit| digit % 2 == 1);
+                first > 5 || (first == 5 && (tail || odd))
+            }
+        }
+    }
+}
+
+/// Adds one to the last digit, returns `true` when a digit was prepended.
+fn increment(digits: &mut Vec<u8>) -> bool {
+    for digit in digits.iter_mut().rev() {
+        if *digit == 9 {
+            *digit = 0;
+        } else {
+            *digit += 1;
+            return false;
+        }
+    }
+    digits.insert(0, 1);
+    digits.pop();
+    true
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{DecimalSeparator, Grouping, NumberFormat, Precision, Rounding};
+
+    fn format(precision: Precision, rounding: Rounding, value: f64) -> String {
+        NumberFormat {
+            precision,
+            rounding,
+            grouping: Grouping::None,
+            decimal: DecimalSeparator::Dot,
+        }
+        .format(value)
+    }
+
+    #[test]
+    fn test_decimals() {
+        let decimals = Precision::Decimals(2);
+        assert_eq!(format(decimals, Rounding::HalfUp, 0.285), "0.29");
+        assert_eq!(format(decimals, Rounding::HalfUp, 9.996), "10.00");
+        assert_eq!(format(decimals, Rounding::HalfUp, -0.001), "0.00");
+        assert_eq!(format(decimals, Rounding::Up, 0.001), "0.01");
+        assert_eq!(format(decimals, Rounding::Down, 1.239), "1.23");
+        assert_eq!(format(decimals, Rounding::HalfEven, 0.125), "0.12");
+        assert_eq!(format(decimals, Rounding::HalfEven, 0.135), "0.14");
+        assert_eq!(format(Precision::Decimals(0), Rounding::HalfUp, 2.5), "3");
+    }
+
+    #[test]
+    fn test_significant() {
+        let significant = Precision::Significant(6);
+        assert_eq!(
+            format(significant, Rounding::HalfUp, std::f64::consts::PI / 4.),
+            "0.785398"
+        );
+        assert_eq!(format(significant, Rounding::HalfUp, 2.), "2");
+        assert_eq!(
+            format(significant, Rounding::HalfUp, 0.0001234567),
+            "0.000123457"
+        );
+        assert_eq!(format(significant, Rounding::HalfUp, 1234567.), "1234567");
+        assert_eq!(format(significant, Rounding::HalfUp, 1234567.89), "1234568");
+        assert_eq!(format(significant, Rounding::Down, -999.9999), "-999.999");
+        assert_eq!(format(significant, Rounding::HalfUp, 0.), "0");
+    }
+
+    #[test]
+    fn test_grouping() {
+        let mut format = NumberFormat {
+            precision: Precision::Decimals(1),
+            rounding: Rounding::HalfUp,
+            grouping: Grouping::Space,
+            decimal: DecimalSeparator::Dot,
+        };
+        assert_eq!(format.format(-1234567.25), "-1 234 567.3");
+        assert_eq!(format.format(123.), "123.0");
+        assert_eq!(format.format(f64::INFINITY), "inf");
+        format.grouping = Grouping::Dot;
+        format.decimal = DecimalSeparator::Comma;
+        assert_eq!(format.format(1234.5), "1.234,5");
+        format.grouping = Grouping::Comma;
+        assert_eq!(format.format(1234.5), "1234,5");
+    }
+}
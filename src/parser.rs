@@ -0,0 +1,288 @@
+//! Parsing of the numeric input fields.
+
+use crate::literals::messages;
+use crate::number_format::NumberFormat;
+use core::f64;
+
+/// Separates a nominal value from its tolerance: `50±0,2`.
+pub(crate) const TOLERANCE: char = '±';
+/// Separates the limits of a range: `49.8..50.2`.
+pub const RANGE: &str = "..";
+
+/// Evaluates a sum of decimal numbers, e.g. `"12,3 - 4.0 + 3"`.
+/// Both `.` and `,` are accepted as decimal separators, the grouping
+/// separator of `format` is removed only where it cannot be one, e.g.
+/// `"1.234,5"`; `"1.234"` could be either and is rejected.
+pub fn split_numbers(input: &str, format: &NumberFormat) -> Result<f64, &'static str> {
+    let mut prepared_input = input.trim_start();
+    let mut positive = if prepared_input.starts_with('-') {
+        prepared_input = prepared_input.strip_prefix('-').ok_or("parse_error")?;
+        false
+    } else {
+        true
+    };
+    let numbers = prepared_input.split_inclusive(is_sign);
+    let mut sum = 0.;
+    for num in numbers {
+        let next_positive = !num.ends_with('-');
+        let trimmed_num = remove_grouping(num.trim_end_matches(is_sign).trim(), format)?;
+        let trimmed_num = trimmed_num.as_str();
+        let mut float_number;
+        if trimmed_num.starts_with(decimal_separator) {
+            float_number = get_fractional(trimmed_num.trim_start_matches(decimal_separator))?;
+        } else {
+            let mut float_parts = trimmed_num.splitn(2, decimal_separator);
+            float_number = float_parts
+                .next()
+                .ok_or(messages::INPUT_WRONG)?
+                .parse::<i64>()
+                .or(Err(messages::PARSE_WRONG))? as f64;
+            if let Some(val) = float_parts.next() {
+                float_number += get_fractional(val)?;
+            }
+        }
+        if !positive {
+            float_number *= -1.0;
+        }
+        positive = next_positive;
+        sum += float_number;
+    }
+    Ok(sum)
+}
+
+/// A dimension with its tolerance limits.
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub struct Range {
+    /// Lower limit.
+    pub min: f64,
+    /// Value used for the area.
+    pub nominal: f64,
+    /// Upper limit.
+    pub max: f64,
+}
+
+/// Parses a dimension with an optional tolerance: `"50±0,2"` or
+/// `"49.8..50.2"` (nominal is the middle of the range). Both limits of a
+/// range must be single numbers, so `"..7"` or `"1.."` is rejected. Without a tolerance it is the same as
+/// [`split_numbers`].
+pub fn split_range(input: &str, format: &NumberFormat) -> Result<Range, &'static str> {
+    if let Some((nominal, tolerance)) = input.split_once(TOLERANCE) {
+        let nominal = split_numbers(nominal, format)?;
+        let tolerance = split_numbers(tolerance, format)?.abs();
+        return Ok(Range {
+            min: nominal - tolerance,
+            nominal,
+            max: nominal + tolerance,
+        });
+    }
+    if let Some((min, max)) = input.split_once(RANGE) {
+        let (min, max) = (range_limit(min, format)?, range_limit(max, format)?);
+        return Ok(Range {
+            min: min.min(max),
+            nominal: (min + max) / 2.,
+            max: min.max(max),
+        });
+    }
+    let nominal = split_numbers(input, format)?;
+    Ok(Range {
+        min: nominal,
+        nominal,
+        max: nominal,
+    })
+}
+
+/// One limit of a range: digits with an optional fractional part, without
+/// signs or a leading or trailing separator.
+fn range_limit(input: &str, format: &NumberFormat) -> Result<f64, &'static str> {
+    let limit = input.trim();
+    let plain = limit.starts_with(|letter: char| letter.is_ascii_digit())
+        && limit.ends_with(|letter: char| letter.is_ascii_digit())
+        && !limit.contains(is_sign);
+    if !plain {
+        return Err(messages::PARSE_WRONG);
+    }
+    split_numbers(limit, format)
+}
+
+/// Removes the grouping separator of `format` from a single number.
+///
+/// Space and apostrophe are removed between digits. A dot or a comma is
+/// removed only when the integer part is grouped by thousands
+/// (`"1.234.567"`, `"1.234,5"`); otherwise it is the decimal separator, so
+/// `"2.5"` stays 2.5. A single group without a decimal part (`"1.234"`)
+/// could be either and is rejected.
+fn remove_grouping(number: &str, format: &NumberFormat) -> Result<String, &'static str> {
+    let Some(separator) = format.grouping_separator() else {
+        return Ok(number.to_string());
+    };
+    if !decimal_separator(separator) {
+        let chars: Vec<char> = number.chars().collect();
+        return Ok(chars
+            .iter()
+            .enumerate()
+            .filter(|(index, letter)| {
+                let between_digits = *index > 0
+                    && chars[index - 1].is_ascii_digit()
+                    && chars.get(index + 1).is_some_and(char::is_ascii_digit);
+                **letter != separator || !between_digits
+            })
+            .map(|(_, letter)| *letter)
+            .collect());
+    }
+    let decimal = format.decimal.char();
+    let (integer, fraction) = match number.split_once(decimal) {
+        Some((integer, fraction)) => (integer, Some(fraction)),
+        None => (number, None),
+    };
+    let groups: Vec<&str> = integer.split(separator).collect();
+    let digits = |group: &str| group.bytes().all(|letter| letter.is_ascii_digit());
+    let grouped = groups.len() > 1
+        && (1..=3).contains(&groups[0].len())
+        && !groups[0].starts_with('0')
+        && digits(groups[0])
+        && groups[1..]
+            .iter()
+            .all(|group| group.len() == 3 && digits(group));
+    if !grouped {
+        return Ok(number.to_string());
+    }
+    if groups.len() == 2 && fraction.is_none() {
+        return Err(messages::AMBIGUOUS_NUMBER);
+    }
+    let mut output = groups.concat();
+    if let Some(fraction) = fraction {
+        output.push(decimal);
+        output.push_str(fraction);
+    }
+    Ok(output)
+}
+
+fn get_fractional(input: &str) -> Result<f64, &'static str> {
+    let len_fractional = input.len();
+    if len_fractional > 0 {
+        let denominator = 10.0_f64.powi(len_fractional as i32);
+        return match input.parse::<i64>() {
+            Ok(num) => Ok(num as f64 / denominator),
+            Err(_) => Err(messages::PARSE_WRONG),
+        };
+    }
+    Ok(0.)
+}
+
+pub(crate) fn decimal_separator(letter: char) -> bool {
+    letter == '.' || letter == ','
+}
+
+pub(crate) fn is_sign(letter: char) -> bool {
+    letter == '+' || letter == '-'
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::literals::messages;
+    use crate::number_format::{DecimalSeparator, Grouping, NumberFormat};
+
+    use super::Range;
+
+    fn split_numbers(input: &str) -> Result<f64, &'static str> {
+        super::split_numbers(input, &NumberFormat::default())
+    }
+
+    fn split_range(input: &str) -> Result<Range, &'static str> {
+        super::split_range(input, &NumberFormat::default())
+    }
+
+    #[test]
+    fn test_parcer_1() {
+        assert_eq!(split_numbers("43"), Ok(43.0));
+    }
+    #[test]
+    fn test_parcer_2() {
+        assert_eq!(split_numbers("43.5"), Ok(43.5));
+    }
+    #[test]
+    fn test_parcer_3() {
+        assert_eq!(split_numbers("4+3"), Ok(7.0));
+    }
+    #[test]
+    fn test_parcer_4() {
+        assert_eq!(split_numbers("4 - 3"), Ok(1.0));
+    }
+    #[test]
+    fn test_parcer_5() {
+        assert_eq!(split_numbers(" -4.0 + 3,0"), Ok(-1.0));
+    }
+    #[test]
+    fn test_parcer_6() {
+        assert_eq!(split_numbers("12,3 - 4.0 + 3,0"), Ok(11.3));
+    }
+    #[test]
+    fn test_parcer_7() {
+        assert_eq!(split_numbers("12,3  4.0"), Err(messages::PARSE_WRONG));
+    }
+    #[test]
+    fn test_parcer_8() {
+        assert_eq!(split_numbers("-- 2,3 - 7"), Err(messages::PARSE_WRONG));
+    }
+    #[test]
+    fn test_parcer_9() {
+        assert_eq!(split_numbers(",3  +.7- ,5"), Ok(0.5));
+    }
+    #[test]
+    fn test_parcer_10() {
+        assert_eq!(split_numbers("0,3 + 1..7"), Err(messages::PARSE_WRONG));
+    }
+
+    #[test]
+    fn test_parcer_11() {
+        assert_eq!(split_numbers("0,,7"), Err(messages::PARSE_WRONG));
+    }
+
+    #[test]
+    fn test_grouping() {
+        for grouping in Grouping::ALL {
+            for decimal in DecimalSeparator::ALL {
+                let format = NumberFormat {
+                    grouping,
+                    decimal,
+                    ..Default::default()
+                };
+                assert_eq!(super::split_numbers("2.5", &format), Ok(2.5));
+                assert_eq!(super::split_numbers("12,5", &format), Ok(12.5));
+                assert_eq!(super::split_numbers("0.125 - 1,5", &format), Ok(-1.375));
+            }
+        }
+        let mut format = NumberFormat {
+            grouping: Grouping::Dot,
+            decimal: DecimalSeparator::Comma,
+            ..Default::default()
+        };
+        assert_eq!(super::split_numbers("1.234,5 - 2.5", &format), Ok(1232.));
+        assert_eq!(super::split_numbers("1.234.567", &format), Ok(1234567.));
+        assert_eq!(
+            super::split_numbers("1.234", &format),
+            Err(messages::AMBIGUOUS_NUMBER)
+        );
+        format.grouping = Grouping::Space;
+        assert_eq!(super::split_numbers("1 234,5 + 1", &format), Ok(1235.5));
+        format.grouping = Grouping::Comma;
+        format.decimal = DecimalSeparator::Dot;
+        assert_eq!(super::split_numbers("12,345.5", &format), Ok(12345.5));
+    }
+
+    #[test]
+    fn test_range() {
+        let range = Range {
+            min: 49.75,
+            nominal: 50.,
+            max: 50.25,
+        };
+        assert_eq!(split_range("50±0,25"), Ok(range));
+        assert_eq!(split_range("49.75..50.25"), Ok(range));
+        assert_eq!(split_range("50.25..49.75"), Ok(range));
+        assert_eq!(split_range("4+3").map(|range| range.max), Ok(7.));
+        assert_eq!(split_range("50±0,2±1"), Err(messages::PARSE_WRONG));
+        assert_eq!(split_range("1..2+3"), Err(messages::PARSE_WRONG));
+        assert_eq!(split_range("1...7"), Err(messages::PARSE_WRONG));
+    }
+}
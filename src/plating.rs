@@ -0,0 +1,136 @@
+//! Electroplating current, time and metal consumption by Faraday's law.
+
+use crate::literals;
+
+/// Faraday constant, C/mol.
+const FARADAY: f64 = 96485.33;
+
+#[derive(Copy, Clone, PartialEq, Debug)]
+/// Metal deposited by electroplating.
+pub enum PlatingMetal {
+    /// Zinc from Zn²⁺.
+    Zinc,
+    /// Nickel from Ni²⁺.
+    Nickel,
+    /// Copper from an acid Cu²⁺ bath.
+    Copper,
+    /// Chromium from Cr⁶⁺.
+    Chromium,
+    /// Tin from Sn²⁺.
+    Tin,
+    /// Silver from Ag⁺.
+    Silver,
+}
+
+impl PlatingMetal {
+    /// Every metal, in the order of the selector.
+    pub const ALL: [PlatingMetal; 6] = [
+        PlatingMetal::Zinc,
+        PlatingMetal::Nickel,
+        PlatingMetal::Copper,
+        PlatingMetal::Chromium,
+        PlatingMetal::Tin,
+        PlatingMetal::Silver,
+    ];
+
+    /// Molar mass in g/mol.
+    pub fn molar_mass(&self) -> f64 {
+        match self {
+            PlatingMetal::Zinc => 65.38,
+            PlatingMetal::Nickel => 58.69,
+            PlatingMetal::Copper => 63.55,
+            PlatingMetal::Chromium => 52.00,
+            PlatingMetal::Tin => 118.71,
+            PlatingMetal::Silver => 107.87,
+        }
+    }
+
+    /// Number of electrons per deposited ion.
+    pub fn valence(&self) -> f64 {
+        match self {
+            PlatingMetal::Zinc
+            | PlatingMetal::Nickel
+            | PlatingMetal::Copper
+            | PlatingMetal::Tin => 2.,
+            PlatingMetal::Chromium => 6.,
+            PlatingMetal::Silver => 1.,
+        }
+    }
+
+    /// Density in g/cm³.
+    pub fn density(&self) -> f64 {
+        match self {
+            PlatingMetal::Zinc => 7.14,
+            PlatingMetal::Nickel => 8.90,
+            PlatingMetal::Copper => 8.96,
+            PlatingMetal::Chromium => 7.19,
+            PlatingMetal::Tin => 7.29,
+            PlatingMetal::Silver => 10.49,
+        }
+    }
+
+    /// Name in the current language.
+    pub fn name(&self) -> &str {
+        literals::tr(match self {
+            PlatingMetal::Zinc => literals::ZINC,
+            PlatingMetal::Nickel => literals::NICKEL,
+            PlatingMetal::Copper => literals::COPPER,
+            PlatingMetal::Chromium => literals::CHROMIUM,
+            PlatingMetal::Tin => literals::TIN,
+            PlatingMetal::Silver => literals::SILVER,
+        })
+    }
+}
+
+#[derive(Copy, Clone, PartialEq, Debug)]
+/// Current, time and metal consumption of a plating job, see [`calculate`].
+pub struct PlatingResult {
+    /// Bath current, A.
+    pub current: f64,
+    /// Plating time, s.
+    pub time: f64,
+    /// Deposited metal, g.
+    pub metal_mass: f64,
+}
+
+/// Plating of `area` dm² at `current_density` A/dm² up to `thickness` µm,
+/// `efficiency` is the cathode current efficiency from 0 to 1.
+pub fn calculate(
+    metal: PlatingMetal,
+    area: f64,
+    current_density: f64,
+    thickness: f64,
+    efficiency: f64,
+) -> PlatingResult {
+    let current = current_density * area;
+    let volume = area * 100. * thickness * 1e-4;
+    let metal_mass = volume * metal.density();
+    let charge = metal_mass * metal.valence() * FARADAY / metal.molar_mass();
+    PlatingResult {
+        current,
+        time: charge / (current * efficiency),
+        metal_mass,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{calculate, PlatingMetal};
+
+    #[test]
+    fn test_zinc() {
+        // 1 dm² of 10 µm zinc at 2 A/dm²
+        let result = calculate(PlatingMetal::Zinc, 1., 2., 10., 1.);
+        assert!((result.current - 2.).abs() < 1e-12);
+        assert!((result.metal_mass - 0.714).abs() < 1e-12);
+        assert!((result.time - 1053.7).abs() < 0.1);
+    }
+
+    #[test]
+    fn test_efficiency() {
+        let full = calculate(PlatingMetal::Nickel, 3., 1.5, 12., 1.);
+        let half = calculate(PlatingMetal::Nickel, 3., 1.5, 12., 0.5);
+        assert!((half.time - 2. * full.time).abs() < 1e-9);
+        assert_eq!(half.metal_mass, full.metal_mass);
+    }
+}
@@ -0,0 +1,72 @@
+//! Quote prices by area.
+
+/// Prices of a quote, `price` is per output area unit of the calculator.
+#[derive(Copy, Clone, PartialEq, Debug, Default)]
+pub struct Pricing {
+    /// Price of one output area unit.
+    pub price: f64,
+    /// Lowest price of one piece, see [`Pricing::line_price`].
+    pub min_charge: f64,
+    /// Fixed amount added once to the quote.
+    pub setup_fee: f64,
+}
+
+impl Pricing {
+    /// Price of a result line of `factor` pieces, each not less than the
+    /// minimum charge. Negative areas (subtracted parts) are priced without
+    /// the minimum.
+    pub fn line_price(&self, area: f64, factor: f64) -> f64 {
+        if area < 0. || factor <= 0. {
+            return area * self.price;
+        }
+        (area / factor * self.price).max(self.min_charge) * factor
+    }
+
+    /// Converts the area price after the areas were multiplied by `factor`.
+    pub fn scale(&mut self, factor: f64) {
+        self.price /= factor;
+    }
+
+    /// Sum of the line prices of `(area, factor)` lines and the setup fee.
+    pub fn quote_total(&self, lines: impl Iterator<Item = (f64, f64)>) -> f64 {
+        lines
+            .map(|(area, factor)| self.line_price(area, factor))
+            .sum::<f64>()
+            + self.setup_fee
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Pricing;
+
+    #[test]
+    fn test_quote() {
+        let pricing = Pricing {
+            price: 2.,
+            min_charge: 5.,
+            setup_fee: 10.,
+        };
+        assert_eq!(pricing.line_price(10., 1.), 20.);
+        assert_eq!(pricing.line_price(1., 1.), 5.);
+        assert_eq!(pricing.line_price(-1., -1.), -2.);
+        // Four pieces of 0.5: the minimum applies to each
+        assert_eq!(pricing.line_price(2., 4.), 20.);
+        assert_eq!(pricing.line_price(40., 4.), 80.);
+        assert_eq!(
+            pricing.quote_total([(10., 1.), (1., 1.), (2., 4.)].into_iter()),
+            55.
+        );
+    }
+
+    #[test]
+    fn test_scale() {
+        // dm² to mm²: areas grow by 10000, the price per unit shrinks
+        let mut pricing = Pricing {
+            price: 100.,
+            ..Default::default()
+        };
+        pricing.scale(10000.);
+        assert_eq!(pricing.line_price(10000., 1.), 100.);
+    }
+}
@@ -0,0 +1,209 @@
+use std::io::Read;
+
+use area_calculator::{
+    literals::{self, tr},
+    shapes,
+};
+use serde::Serialize;
+use tiny_http::{Header, Method, Response, Server};
+
+use crate::batch;
+
+pub const FLAG: &str = "--serve";
+const DEFAULT_ADDRESS: &str = "127.0.0.1:8080";
+/// Largest request body read, in bytes.
+const BODY_LIMIT: u64 = 1024 * 1024;
+
+#[derive(Serialize)]
+struct ShapeSchema {
+    key: &'static str,
+    name: String,
+    fields: Vec<FieldSchema>,
+}
+
+#[derive(Serialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+enum FieldSchema {
+    Input {
+        label: &'static str,
+    },
+    Optional {
+        label: &'static str,
+    },
+    Factor {
+        label: &'static str,
+    },
+    CheckBox {
+        label: &'static str,
+    },
+    Choice {
+        label: &'static str,
+        options: Vec<&'static str>,
+    },
+    Count {
+        label: &'static str,
+        min: u32,
+        max: u32,
+    },
+    Group {
+        label: &'static str,
+        fields: Vec<FieldSchema>,
+    },
+}
+
+pub fn run(address: Option<&str>) -> i32 {
+    let address = address.unwrap_or(DEFAULT_ADDRESS);
+    let server = match Server::http(address) {
+        Ok(server) => server,
+        Err(err) => {
+            eprintln!("{}", err);
+            return 1;
+        }
+    };
+    println!("Listening on http://{}", address);
+    for mut request in server.incoming_requests() {
+        let (status, content) = match read_body(request.as_reader()) {
+            Ok(body) => handle(request.method(), request.url(), &body),
+            Err(response) => response,
+        };
+        let response = Response::from_string(content)
+            .with_status_code(status)
+            .with_header(
+                Header::from_bytes("Content-Type", "application/json")
+                    .expect("static header is valid"),
+            );
+        if let Err(err) = request.respond(response) {
+            eprintln!("{}", err);
+        }
+    }
+    0
+}
+
+/// Reads at most [`BODY_LIMIT`] bytes, a larger body is refused with 413.
+fn read_body(reader: impl Read) -> Result<String, (u16, String)> {
+    let mut body = String::new();
+    reader
+        .take(BODY_LIMIT + 1)
+        .read_to_string(&mut body)
+        .map_err(|err| (400, error(&err.to_string())))?;
+    if body.len() as u64 > BODY_LIMIT {
+        return Err((413, error("Payload too large")));
+    }
+    Ok(body)
+}
+
+fn handle(method: &Method, url: &str, body: &str) -> (u16, String) {
+    let path = url.split('?').next().unwrap_or_default();
+    match (method, path) {
+        (Method::Get, "/shapes") => (200, to_json(&shape_schemas())),
+        (Method::Post, "/calculate") => calculate(body),
+        (_, "/shapes") | (_, "/calculate") => (405, error("Method not allowed")),
+        _ => (404, error("Not found")),
+    }
+}
+
+fn calculate(body: &str) -> (u16, String) {
+    let parsed = match serde_json::from_str::<serde_json::Value>(body) {
+        Ok(parts @ serde_json::Value::Array(_)) => {
+            serde_json::from_value::<Vec<batch::Part>>(parts)
+                .map(|parts| to_json(&parts.iter().map(batch::calculate_part).collect::<Vec<_>>()))
+        }
+        Ok(part) => serde_json::from_value::<batch::Part>(part)
+            .map(|part| to_json(&batch::calculate_part(&part))),
+        Err(err) => Err(err),
+    };
+    match parsed {
+        Ok(content) => (200, content),
+        Err(err) => (400, error(&err.to_string())),
+    }
+}
+
+fn shape_schemas() -> Vec<ShapeSchema> {
+    shapes::get_shapes()
+        .iter_mut()
+        .map(|shape| ShapeSchema {
+            key: shape.key(),
+            name: shape.name().to_string(),
+            fields: shape.form_state().iter().map(field_schema).collect(),
+        })
+        .collect()
+}
+
+fn field_schema(field: &shapes::FormElement) -> FieldSchema {
+    match field {
+        shapes::FormElement::InputField(label, _) => FieldSchema::Input { label: tr(label) },
+        shapes::FormElement::OptionalField(label, _) => FieldSchema::Optional { label: tr(label) },
+        shapes::FormElement::FactorField(_) => FieldSchema::Factor {
+            label: tr(literals::FACTOR),
+        },
+        shapes::FormElement::CheckBox(label, _) => FieldSchema::CheckBox { label: tr(label) },
+        shapes::FormElement::Choice(label, options, _) => FieldSchema::Choice {
+            label: tr(label),
+            options: options.iter().map(|option| tr(option)).collect(),
+        },
+        shapes::FormElement::Count(label, _, range) => FieldSchema::Count {
+            label: tr(label),
+            min: *range.start(),
+            max: *range.end(),
+        },
+        shapes::FormElement::Group(label, template, _) => FieldSchema::Group {
+            label: tr(label),
+            fields: template.iter().map(field_schema).collect(),
+        },
+    }
+}
+
+fn to_json<T: Serialize>(value: &T) -> String {
+    serde_json::to_string(value).unwrap_or_else(|err| error(&err.to_string()))
+}
+
+fn error(message: &str) -> String {
+    serde_json::json!({ "error": message }).to_string()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{handle, read_body, BODY_LIMIT};
+    use serde_json::json;
+    use tiny_http::Method;
+
+    fn request(method: Method, url: &str, body: &str) -> (u16, serde_json::Value) {
+        let (status, content) = handle(&method, url, body);
+        (status, serde_json::from_str(&content).unwrap())
+    }
+
+    #[test]
+    fn test_shapes() {
+        let (status, content) = request(Method::Get, "/shapes", "");
+        assert_eq!(status, 200);
+        let cylinder = &content.as_array().unwrap()[2];
+        assert_eq!(cylinder["key"], json!("cylinder"));
+        assert_eq!(cylinder["fields"][0]["type"], json!("input"));
+        assert_eq!(cylinder["fields"][2]["type"], json!("factor"));
+        assert_eq!(cylinder["fields"][3]["type"], json!("check_box"));
+    }
+
+    #[test]
+    fn test_calculate() {
+        let body = json!({"shape": "rectangle", "dimensions": [100, 200]}).to_string();
+        let (status, content) = request(Method::Post, "/calculate", &body);
+        assert_eq!(status, 200);
+        assert_eq!(content["area"], json!(2.0));
+
+        let body = json!([{"shape": "rectangle", "dimensions": [100, 200]}]).to_string();
+        let (status, content) = request(Method::Post, "/calculate", &body);
+        assert_eq!(status, 200);
+        assert_eq!(content[0]["area"], json!(2.0));
+    }
+
+    #[test]
+    fn test_errors() {
+        assert_eq!(request(Method::Post, "/calculate", "{").0, 400);
+        assert_eq!(request(Method::Get, "/calculate", "").0, 405);
+        assert_eq!(request(Method::Get, "/", "").0, 404);
+        let body = vec![b' '; BODY_LIMIT as usize];
+        assert!(read_body(body.as_slice()).is_ok());
+        let body = vec![b' '; BODY_LIMIT as usize + 1];
+        assert_eq!(read_body(body.as_slice()).unwrap_err().0, 413);
+    }
+}
@@ -1,61 +1,312 @@
+//! Shapes, their input forms and calculated results.
+
+mod custom;
 mod shape_list;
 
-use crate::literals::messages;
-use shape_list::*;
+use std::ops::RangeInclusive;
+use std::sync::atomic::{AtomicU64, Ordering};
+
+use crate::literals::{self, messages};
+use crate::measure;
+use crate::number_format::{self, NumberFormat};
+use crate::parser;
+pub use custom::{custom_shapes, load_custom_shapes, CustomShape};
+pub use shape_list::{
+    AreaBushing, AreaCircle, AreaCuboid, AreaCylinder, AreaFastener, AreaHexagon, AreaHexagonPrism,
+    AreaRectangle,
+};
 
+/// One control of a shape input form.
 #[derive(Clone)]
 pub enum FormElement {
+    /// Option toggled by a checkbox: label key (see [`crate::literals`]) and state.
     CheckBox(&'static str, bool),
+    /// Length input: label key and raw text, e.g. `"12,5 - 2"`.
     InputField(&'static str, String),
+    /// Length input that may stay empty: label key and raw text. Filled
+    /// from the dimensions left after the input fields.
+    OptionalField(&'static str, String),
+    /// Choice from a list: label key, option label keys and the index of
+    /// the selected option.
+    Choice(&'static str, &'static [&'static str], usize),
+    /// Whole number, e.g. a count of holes: label key, value and the
+    /// allowed values.
+    Count(&'static str, u32, RangeInclusive<u32>),
+    /// Multiplier applied to the area, empty means 1.
     FactorField(String),
-    NoElement,
+    /// Repeatable fields, e.g. the holes of a plate: label key, the fields
+    /// of a new entry and the entries.
+    Group(&'static str, Vec<FormElement>, Vec<Vec<FormElement>>),
 }
 
-trait InnerImplShape {
-    fn parse_input(&mut self, input_factor: f64) -> Result<(), &'static str>;
+/// Intermediate value of a calculation, see [`InnerImplShape::get_steps`].
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub enum Step {
+    /// Partial area in mm²: label key and value.
+    Area(&'static str, f64),
+    /// Dimensionless multiplier: label key and value.
+    Multiplier(&'static str, f64),
+}
+
+/// Formulas of a shape.
+///
+/// Any `Clone + 'static` type implementing this trait is an [`AreaShape`].
+/// Lengths passed around are in millimetres, areas in mm².
+pub trait InnerImplShape {
+    /// Reads the form into the shape, lengths are multiplied by `input_factor`
+    /// and grouped numbers are read with `format`.
+    fn parse_input(&mut self, input_factor: f64, format: &NumberFormat)
+        -> Result<(), &'static str>;
+    /// Area of the parsed shape in mm².
     fn get_area(&self) -> f64;
-    fn get_result(&self, input_factor: f64, area: f64) -> String;
-    fn state(&mut self) -> &mut [FormElement; 6];
-    fn get_name(&self) -> &str;
+    /// Volume of the parsed shape in mm³, `None` for flat shapes.
+    fn get_volume(&self) -> Option<f64> {
+        None
+    }
+    /// Human readable result line for an `area` already in output units,
+    /// the area rounded by `format` and the dimensions shown as entered.
+    fn get_result(&self, input_factor: f64, area: f64, format: &NumberFormat) -> String;
+    /// Area formula in the letters of the result line, e.g. `S = l·h·k`.
+    fn get_formula(&self) -> &'static str;
+    /// Partial areas and multipliers of the parsed shape, the factor last:
+    /// the area is the sum of the partial areas times the multipliers.
+    fn get_steps(&self) -> Vec<Step>;
+    /// Input form of the shape.
+    fn state(&mut self) -> &mut [FormElement];
+    /// Display name.
+    fn get_name(&self) -> &'static str;
+    /// Stable identifier, independent of the interface language.
+    fn get_key(&self) -> &'static str;
 }
 
-trait AreaShapeClone: InnerImplShape {
+/// Object safe cloning of boxed shapes, implemented for every [`AreaShape`].
+pub trait AreaShapeClone: InnerImplShape {
+    /// Boxed copy of the shape with its form.
     fn duplicate(&self) -> Box<dyn AreaShape>;
 }
 
-#[allow(private_bounds)]
+/// A shape whose area can be calculated from its input form.
 pub trait AreaShape: AreaShapeClone {
-    fn form_state(&mut self) -> &mut [FormElement; 6] {
+    /// Input form, to be edited before [`AreaShape::calculate`].
+    fn form_state(&mut self) -> &mut [FormElement] {
         self.state()
     }
 
+    /// Removes typed characters that cannot be part of a number from the
+    /// form, keeping the grouping separator of `format`.
+    fn validate_form(&mut self, format: &NumberFormat) {
+        shape_list::helpers::std_validate_state(self.state(), format);
+    }
+
+    /// Name in the current language.
     fn name(&self) -> &str {
-        self.get_name()
+        literals::tr(self.get_name())
+    }
+
+    /// Stable identifier, independent of the interface language.
+    fn key(&self) -> &'static str {
+        self.get_key()
     }
 
+    /// Replaces the text of the factor field.
+    fn set_factor(&mut self, factor: &str) {
+        for field in self.state().iter_mut() {
+            if let FormElement::FactorField(txt) = field {
+                *txt = factor.to_string();
+            }
+        }
+    }
+
+    /// Multiplies the factor field, an empty factor counts as 1.
+    fn multiply_factor(
+        &mut self,
+        multiplier: f64,
+        format: &NumberFormat,
+    ) -> Result<(), &'static str> {
+        for field in self.state().iter_mut() {
+            if let FormElement::FactorField(txt) = field {
+                let factor = if txt.trim().is_empty() {
+                    1.
+                } else {
+                    parser::split_numbers(txt, format)?
+                };
+                let factor = (factor * multiplier * 1e9).round() / 1e9;
+                let text = number_format::input_text(factor, format);
+                // Too large factors could not be read back.
+                parser::split_numbers(&text, format).map_err(|_| messages::INPUT_WRONG)?;
+                *txt = text;
+            }
+        }
+        Ok(())
+    }
+
+    /// Fills the form: input fields and counts in order from `dimensions`,
+    /// the factor field from `factor`, checks the options and selects the
+    /// choices named by `options`, by key, translation or a unique prefix
+    /// of either. A group takes all the dimensions left, one entry per the
+    /// number of its input fields and counts.
+    fn fill_form(
+        &mut self,
+        dimensions: &[&str],
+        factor: &str,
+        options: &[&str],
+    ) -> Result<(), &'static str> {
+        let labels: Vec<&'static str> = self
+            .state()
+            .iter()
+            .flat_map(|field| match field {
+                FormElement::CheckBox(label, _) => std::slice::from_ref(label),
+                FormElement::Choice(_, labels, _) => labels,
+                _ => &[],
+            })
+            .copied()
+            .collect();
+        let selected = options
+            .iter()
+            .map(|option| find_option(&labels, option))
+            .collect::<Result<Vec<_>, _>>()?;
+        let mut dimensions = dimensions.iter();
+        for field in self.state().iter_mut() {
+            match field {
+                FormElement::InputField(..) | FormElement::Count(..) => {
+                    set_value(field, dimensions.next().ok_or(messages::WRONG_DIMENSIONS)?)?;
+                }
+                FormElement::OptionalField(_, txt) => {
+                    *txt = dimensions.next().unwrap_or(&"").to_string();
+                }
+                FormElement::Choice(_, labels, selected_label) => {
+                    *selected_label = labels
+                        .iter()
+                        .position(|label| selected.contains(label))
+                        .unwrap_or(0);
+                }
+                FormElement::FactorField(txt) => {
+                    *txt = factor.to_string();
+                }
+                FormElement::CheckBox(label, state) => {
+                    *state = selected.contains(label);
+                }
+                FormElement::Group(_, template, entries) => {
+                    let size = template.iter().filter(|field| is_input(field)).count();
+                    let values: Vec<&str> = dimensions.by_ref().copied().collect();
+                    if size == 0 || values.len() % size != 0 {
+                        return Err(messages::WRONG_DIMENSIONS);
+                    }
+                    *entries = values
+                        .chunks(size)
+                        .map(|values| {
+                            let mut entry = template.clone();
+                            let mut values = values.iter();
+                            for field in entry.iter_mut().filter(|field| is_input(field)) {
+                                set_value(field, values.next().unwrap_or(&""))?;
+                            }
+                            Ok(entry)
+                        })
+                        .collect::<Result<_, _>>()?;
+                }
+            }
+        }
+        if dimensions.next().is_some() {
+            return Err(messages::WRONG_DIMENSIONS);
+        }
+        Ok(())
+    }
+
+    /// Parses the form and calculates the area, converting input lengths
+    /// with `input_factor` and the area with `output_factor`
+    /// (see [`crate::measure`]). Numbers are read and the result line is
+    /// written with `format`.
     fn calculate(
         &mut self,
         input_factor: f64,
         output_factor: f64,
+        format: &NumberFormat,
     ) -> Result<CalculationResult, &'static str> {
-        self.parse_input(input_factor)?;
+        self.parse_input(input_factor, format)?;
         let area = self.get_area() / output_factor;
         if !area.is_finite() {
             return Err(messages::CALCULATION_ERR);
         }
-        let mut result = self.get_result(input_factor, area);
-        localize(&mut result);
+        let result = self.get_result(input_factor, area, format);
+        let volume = self.get_volume();
+        let range = self
+            .area_range(input_factor, format)?
+            .map(|(min, max)| (min / output_factor, max / output_factor));
+        let factor = self
+            .get_steps()
+            .iter()
+            .find_map(|step| match step {
+                Step::Multiplier(literals::FACTOR, value) => Some(*value),
+                _ => None,
+            })
+            .unwrap_or(1.);
         Ok(CalculationResult {
+            id: next_result_id(),
             area,
+            range,
+            volume,
+            factor,
+            group: 0,
+            label: String::new(),
+            note: String::new(),
             result,
             shape: self.duplicate(),
         })
     }
 
-    fn update_result(&mut self, input_factor: f64, area: f64) -> String {
-        let mut result = self.get_result(input_factor, area);
-        localize(&mut result);
-        result
+    /// Smallest and largest area within the dimension tolerances, `None`
+    /// when no dimension has a tolerance. Dimensions of group entries, e.g.
+    /// hole diameters, are included. Each toleranced dimension is
+    /// varied alone to find whether it increases or reduces the area, then
+    /// the two corners with all dimensions at their area increasing and
+    /// reducing limits are calculated. Limits that fail to calculate, e.g.
+    /// a bushing whose bore reaches the outer diameter, are skipped.
+    fn area_range(
+        &mut self,
+        input_factor: f64,
+        format: &NumberFormat,
+    ) -> Result<Option<(f64, f64)>, &'static str> {
+        let mut ranges = Vec::new();
+        for (index, txt) in length_texts(self.state()).into_iter().enumerate() {
+            let range = parser::split_range(txt, format)?;
+            if range.min != range.max {
+                ranges.push((index, range));
+            }
+        }
+        if ranges.is_empty() {
+            return Ok(None);
+        }
+        let nominal = self.get_area();
+        let (mut min, mut max) = (nominal, nominal);
+        let mut lower = Vec::new();
+        let mut upper = Vec::new();
+        for (index, range) in ranges.iter() {
+            let at_min = area_with(self, input_factor, format, &[(*index, range.min)]);
+            let at_max = area_with(self, input_factor, format, &[(*index, range.max)]);
+            for area in at_min.iter().chain(at_max.iter()) {
+                min = min.min(*area);
+                max = max.max(*area);
+            }
+            if at_max.unwrap_or(nominal) >= at_min.unwrap_or(nominal) {
+                lower.push((*index, range.min));
+                upper.push((*index, range.max));
+            } else {
+                lower.push((*index, range.max));
+                upper.push((*index, range.min));
+            }
+        }
+        for corner in [lower, upper] {
+            if let Some(area) = area_with(self, input_factor, format, &corner) {
+                min = min.min(area);
+                max = max.max(area);
+            }
+        }
+        Ok(Some((min, max)))
+    }
+
+    /// Result line for a known `area`, used after unit or format changes.
+    fn update_result(&mut self, input_factor: f64, area: f64, format: &NumberFormat) -> String {
+        self.get_result(input_factor, area, format)
     }
 }
 
@@ -70,8 +321,10 @@ where
 
 impl<T> AreaShape for T where T: InnerImplShape + Clone + 'static {}
 
+/// All built-in shapes with empty forms, followed by the custom shapes
+/// (see [`load_custom_shapes`]).
 pub fn get_shapes() -> Vec<Box<dyn AreaShape>> {
-    vec![
+    let mut shapes: Vec<Box<dyn AreaShape>> = vec![
         Box::<AreaCircle>::default(),
         Box::<AreaRectangle>::default(),
         Box::<AreaCylinder>::default(),
@@ -79,70 +332,597 @@ pub fn get_shapes() -> Vec<Box<dyn AreaShape>> {
         Box::<AreaHexagonPrism>::default(),
         Box::<AreaBushing>::default(),
         Box::<AreaCuboid>::default(),
-    ]
+        Box::<AreaFastener>::default(),
+    ];
+    for shape in custom_shapes() {
+        shapes.push(Box::new(shape));
+    }
+    shapes
+}
+
+/// Finds a shape by its key or display name, ignoring case.
+pub fn find_shape<'a>(
+    shapes: &'a mut [Box<dyn AreaShape>],
+    key: &str,
+) -> Option<&'a mut Box<dyn AreaShape>> {
+    shapes
+        .iter_mut()
+        .find(|shape| shape.key() == key || shape.name().to_lowercase() == key.to_lowercase())
+}
+
+/// Texts of the length fields in form order, including the fields of
+/// group entries.
+fn length_texts(state: &mut [FormElement]) -> Vec<&mut String> {
+    let mut texts = Vec::new();
+    for field in state {
+        match field {
+            FormElement::InputField(_, txt) | FormElement::OptionalField(_, txt) => texts.push(txt),
+            FormElement::Group(_, _, entries) => {
+                for entry in entries {
+                    texts.extend(length_texts(entry));
+                }
+            }
+            _ => {}
+        }
+    }
+    texts
 }
 
+/// Area of a copy of the shape with the length fields (indices of
+/// [`length_texts`]) at `values`, `None` when it cannot be calculated.
+fn area_with(
+    shape: &(impl AreaShape + ?Sized),
+    input_factor: f64,
+    format: &NumberFormat,
+    values: &[(usize, f64)],
+) -> Option<f64> {
+    let mut shape = shape.duplicate();
+    let mut texts = length_texts(shape.state());
+    for (index, value) in values {
+        *texts[*index] = number_format::input_text(*value, format);
+    }
+    shape.parse_input(input_factor, format).ok()?;
+    Some(shape.get_area()).filter(|area| area.is_finite())
+}
+
+/// Fields filled from the dimensions by [`AreaShape::fill_form`].
+fn is_input(field: &FormElement) -> bool {
+    matches!(
+        field,
+        FormElement::InputField(..) | FormElement::OptionalField(..) | FormElement::Count(..)
+    )
+}
+
+fn set_value(field: &mut FormElement, value: &str) -> Result<(), &'static str> {
+    match field {
+        FormElement::InputField(_, txt) | FormElement::OptionalField(_, txt) => {
+            *txt = value.to_string();
+        }
+        FormElement::Count(_, count, range) => {
+            let value = value.trim().parse().map_err(|_| messages::PARSE_WRONG)?;
+            if !range.contains(&value) {
+                return Err(messages::INPUT_WRONG);
+            }
+            *count = value;
+        }
+        _ => return Err(messages::WRONG_FIELD),
+    }
+    Ok(())
+}
+
+/// Checkbox or choice label named by `option`: the label whose key or
+/// translation equals it, otherwise the only one starting with it.
+fn find_option(labels: &[&'static str], option: &str) -> Result<&'static str, &'static str> {
+    let option = option.to_lowercase();
+    let names = |label: &&'static str| [label.to_lowercase(), literals::tr(label).to_lowercase()];
+    if let Some(label) = labels.iter().find(|label| names(label).contains(&option)) {
+        return Ok(label);
+    }
+    let mut matches = labels.iter().filter(|label| {
+        !option.is_empty() && names(label).iter().any(|name| name.starts_with(&option))
+    });
+    match (matches.next(), matches.next()) {
+        (Some(label), None) => Ok(label),
+        (Some(_), Some(_)) => Err(messages::AMBIGUOUS_OPTION),
+        (None, _) => Err(messages::UNKNOWN_OPTION),
+    }
+}
+
+static RESULT_ID: AtomicU64 = AtomicU64::new(0);
+
+fn next_result_id() -> u64 {
+    RESULT_ID.fetch_add(1, Ordering::Relaxed)
+}
+
+/// Calculated area together with a copy of the shape it came from.
 pub struct CalculationResult {
+    id: u64,
     area: f64,
+    range: Option<(f64, f64)>,
+    volume: Option<f64>,
+    factor: f64,
+    group: usize,
+    label: String,
+    note: String,
     result: String,
     shape: Box<dyn AreaShape>,
 }
 
+impl Clone for CalculationResult {
+    fn clone(&self) -> Self {
+        Self {
+            id: self.id,
+            area: self.area,
+            range: self.range,
+            volume: self.volume,
+            factor: self.factor,
+            group: self.group,
+            label: self.label.clone(),
+            note: self.note.clone(),
+            result: self.result.clone(),
+            shape: self.shape.duplicate(),
+        }
+    }
+}
+
 impl CalculationResult {
+    /// Identifies the result line across moves, undo and recalculation.
+    /// Clones share it, see [`Self::new_id`].
+    pub fn get_id(&self) -> u64 {
+        self.id
+    }
+
+    /// Gives a copy of a result its own id.
+    pub fn new_id(&mut self) {
+        self.id = next_result_id();
+    }
+
+    /// Result line with the name, area and dimensions.
     pub fn get_result(&self) -> &str {
         self.result.as_str()
     }
 
+    /// Area in the output units of the calculation.
     pub fn get_area(&self) -> f64 {
         self.area
     }
 
+    /// Minimum and maximum area within the dimension tolerances.
+    pub fn get_range(&self) -> Option<(f64, f64)> {
+        self.range
+    }
+
+    /// Volume in mm³ for solid shapes.
+    pub fn get_volume(&self) -> Option<f64> {
+        self.volume
+    }
+
+    /// Factor `k` of the form, the number of pieces.
+    pub fn get_factor(&self) -> f64 {
+        self.factor
+    }
+
+    /// Mass in kg for a density in kg/mm³.
+    pub fn get_mass(&self, density: f64) -> Option<f64> {
+        self.volume.map(|volume| volume * density)
+    }
+
+    /// Index of the result group in the calculator.
+    pub fn get_group(&self) -> usize {
+        self.group
+    }
+
+    /// Moves the result into a group of the calculator.
+    pub(crate) fn set_group(&mut self, group: usize) {
+        self.group = group;
+    }
+
+    /// User label, e.g. a drawing number.
+    pub fn get_label(&self) -> &str {
+        self.label.as_str()
+    }
+
+    /// User note, e.g. a customer or an order.
+    pub fn get_note(&self) -> &str {
+        self.note.as_str()
+    }
+
+    /// Label and note for editing.
+    pub fn details_mut(&mut self) -> (&mut String, &mut String) {
+        (&mut self.label, &mut self.note)
+    }
+
+    /// Takes the id, group, label and note of the result this one replaces.
+    pub fn copy_details(&mut self, other: &CalculationResult) {
+        self.id = other.id;
+        self.group = other.group;
+        self.label = other.label.clone();
+        self.note = other.note.clone();
+    }
+
+    /// The shape, with the form used for this result.
     pub fn get_state(&mut self) -> &mut Box<dyn AreaShape> {
         &mut self.shape
     }
 
-    pub fn scale_area(&mut self, factor: f64) {
+    /// Converts the area and its range to other units.
+    pub(crate) fn scale_area(&mut self, factor: f64) {
         self.area *= factor;
+        self.range = self.range.map(|(min, max)| (min * factor, max * factor));
     }
 
-    pub fn update_result(&mut self, input_factor: f64) {
+    /// Working of the result, one line each: the formula, the length
+    /// conversion, the partial areas and multipliers, the area in mm² and
+    /// its conversion into `output_units`.
+    pub fn get_breakdown(
+        &self,
+        input_units: measure::LengthUnits,
+        output_units: measure::AreaUnits,
+        format: &NumberFormat,
+    ) -> Vec<String> {
+        let mm = literals::tr(literals::MM);
+        let mm2 = literals::tr(literals::MM2);
+        let mut lines = vec![self.shape.get_formula().to_string()];
+        if input_units != measure::LengthUnits::MM {
+            lines.push(format!(
+                "1 {} = {} {}",
+                input_units.name(),
+                number_format::number(input_units.value(), format),
+                mm
+            ));
+        }
+        for step in self.shape.get_steps() {
+            lines.push(match step {
+                Step::Area(label, value) => format!(
+                    "{}: {} {}",
+                    literals::tr(label),
+                    number_format::number(value, format),
+                    mm2
+                ),
+                Step::Multiplier(label, value) => {
+                    format!(
+                        "{}: ×{}",
+                        literals::tr(label),
+                        number_format::number(value, format)
+                    )
+                }
+            });
+        }
+        lines.push(format!(
+            "{}: {} {}",
+            literals::tr(literals::AREA),
+            number_format::number(self.shape.get_area(), format),
+            mm2
+        ));
+        if output_units != measure::AreaUnits::MM2 {
+            lines.push(format!(
+                "1 {} = {} {}",
+                output_units.name(),
+                number_format::number(output_units.value(), format),
+                mm2
+            ));
+            lines.push(format!(
+                "{}: {} {}",
+                literals::tr(literals::AREA),
+                number_format::number(self.area, format),
+                output_units.name()
+            ));
+        }
+        lines
+    }
+
+    /// Rebuilds the result line for new input units or a new format.
+    pub fn update_result(&mut self, input_factor: f64, format: &NumberFormat) {
         let area = self.area;
-        self.result = self.get_state().update_result(input_factor, area);
+        self.result = self.get_state().update_result(input_factor, area, format);
     }
 }
 
-const COMMA: u8 = 44;
-const DOT: u8 = 46;
+#[cfg(test)]
+mod tests {
+    use super::{find_shape, get_shapes, AreaShape, FormElement, InnerImplShape, Step};
+    use crate::literals::messages;
+    use crate::number_format::NumberFormat;
+    use crate::parser;
 
-pub fn localize(result: &mut String) {
-    if cfg!(feature = "lang_rus") {
-        unsafe {
-            let mut digit = false;
-            result.as_mut_vec().iter_mut().for_each(|ch| {
-                if *ch == DOT && digit {
-                    *ch = COMMA;
-                } else {
-                    digit = ch.is_ascii_digit();
+    #[test]
+    fn test_fill_form() {
+        let mut shapes = get_shapes();
+        let cylinder = find_shape(&mut shapes, "cylinder").unwrap();
+        cylinder.fill_form(&["10", "20"], "2", &["thread"]).unwrap();
+        assert!(matches!(
+            cylinder.form_state()[3],
+            FormElement::CheckBox(_, true)
+        ));
+        cylinder
+            .fill_form(&["10", "20"], "", &["thread", "trap"])
+            .unwrap();
+        assert!(matches!(
+            cylinder.form_state()[5],
+            FormElement::Choice(_, _, 1)
+        ));
+        assert_eq!(
+            cylinder.fill_form(&["10", "20"], "", &["t"]),
+            Err(messages::AMBIGUOUS_OPTION)
+        );
+        assert_eq!(
+            cylinder.fill_form(&["10", "20"], "", &["none"]),
+            Err(messages::UNKNOWN_OPTION)
+        );
+        assert_eq!(
+            cylinder.fill_form(&["10", "20", "1.5", "30"], "", &[]),
+            Err(messages::WRONG_DIMENSIONS)
+        );
+    }
+
+    #[test]
+    fn test_multiply_factor() {
+        let mut shapes = get_shapes();
+        let circle = find_shape(&mut shapes, "circle").unwrap();
+        circle.fill_form(&["10"], "", &[]).unwrap();
+        circle
+            .multiply_factor(2.5, &NumberFormat::default())
+            .unwrap();
+        circle
+            .multiply_factor(2., &NumberFormat::default())
+            .unwrap();
+        assert!(
+            matches!(&circle.form_state()[1], FormElement::FactorField(factor) if factor == "5")
+        );
+        circle
+            .multiply_factor(1e9, &NumberFormat::default())
+            .unwrap();
+        assert_eq!(
+            circle.multiply_factor(1e10, &NumberFormat::default()),
+            Err(messages::INPUT_WRONG)
+        );
+        assert!(matches!(
+            &circle.form_state()[1],
+            FormElement::FactorField(factor) if factor == "5000000000"
+        ));
+    }
+
+    #[test]
+    fn test_result_dimensions() {
+        let mut shapes = get_shapes();
+        let circle = find_shape(&mut shapes, "circle").unwrap();
+        circle.fill_form(&["1234567"], "", &[]).unwrap();
+        let result = circle.calculate(1., 1., &NumberFormat::default()).unwrap();
+        assert_eq!(
+            result.get_result(),
+            "Circle S=1197069069832 (d:1234567, k:1)"
+        );
+    }
+
+    #[test]
+    fn test_thread_pitch() {
+        let mut shapes = get_shapes();
+        let cylinder = find_shape(&mut shapes, "cylinder").unwrap();
+        cylinder
+            .fill_form(&["10", "20", "1.5"], "", &["threaded"])
+            .unwrap();
+        let area = cylinder
+            .calculate(1., 1., &NumberFormat::default())
+            .unwrap()
+            .get_area();
+        let pitch_diameter = 10. - 3. * f64::sqrt(3.) / 8. * 1.5;
+        assert!((area - std::f64::consts::PI * pitch_diameter * 20. * 1.625).abs() < 1e-9);
+        cylinder
+            .fill_form(&["10", "20"], "", &["threaded"])
+            .unwrap();
+        let area = cylinder
+            .calculate(1., 1., &NumberFormat::default())
+            .unwrap()
+            .get_area();
+        assert!((area - std::f64::consts::PI * 10. * 20. * 1.5).abs() < 1e-9);
+        cylinder
+            .fill_form(&["1", "20", "2"], "", &["threaded"])
+            .unwrap();
+        assert!(matches!(
+            cylinder.calculate(1., 1., &NumberFormat::default()),
+            Err(messages::WRONG_PITCH)
+        ));
+    }
+
+    /// Strips of one length and several widths, each width repeated
+    /// `count` times.
+    #[derive(Clone)]
+    struct Strips {
+        state: Vec<FormElement>,
+        area: f64,
+    }
+
+    impl Default for Strips {
+        fn default() -> Self {
+            Self {
+                state: vec![
+                    FormElement::InputField("length", String::new()),
+                    FormElement::Group(
+                        "strip",
+                        vec![
+                            FormElement::InputField("width", String::new()),
+                            FormElement::Count("count", 1, 1..=9),
+                        ],
+                        Vec::new(),
+                    ),
+                ],
+                area: 0.,
+            }
+        }
+    }
+
+    impl InnerImplShape for Strips {
+        fn parse_input(
+            &mut self,
+            input_factor: f64,
+            format: &NumberFormat,
+        ) -> Result<(), &'static str> {
+            let number = |field: &FormElement| match field {
+                FormElement::InputField(_, txt) => {
+                    parser::split_range(txt, format).map(|range| range.nominal * input_factor)
                 }
-            });
+                _ => Err(messages::WRONG_FIELD),
+            };
+            let length = number(&self.state[0])?;
+            let FormElement::Group(_, _, entries) = &self.state[1] else {
+                return Err(messages::WRONG_FIELD);
+            };
+            self.area = 0.;
+            for entry in entries {
+                let FormElement::Count(_, count, _) = entry[1] else {
+                    return Err(messages::WRONG_FIELD);
+                };
+                self.area += length * number(&entry[0])? * f64::from(count);
+            }
+            Ok(())
+        }
+
+        fn get_area(&self) -> f64 {
+            self.area
+        }
+
+        fn get_result(&self, _: f64, area: f64, _: &NumberFormat) -> String {
+            area.to_string()
+        }
+
+        fn get_formula(&self) -> &'static str {
+            "S = l·Σn·w"
+        }
+
+        fn get_steps(&self) -> Vec<Step> {
+            vec![Step::Area("strips", self.area)]
+        }
+
+        fn state(&mut self) -> &mut [FormElement] {
+            &mut self.state
+        }
+
+        fn get_name(&self) -> &'static str {
+            "strips"
+        }
+
+        fn get_key(&self) -> &'static str {
+            "strips"
         }
     }
-}
 
-#[cfg(test)]
-mod tests {
-    use super::COMMA;
-    use super::DOT;
+    #[test]
+    fn test_group() {
+        let mut strips = Strips::default();
+        strips
+            .fill_form(&["100", "10", "2", "20", "1"], "", &[])
+            .unwrap();
+        assert!(
+            matches!(&strips.form_state()[1], FormElement::Group(_, _, entries) if entries.len() == 2)
+        );
+        let result = strips.calculate(1., 1., &NumberFormat::default()).unwrap();
+        assert_eq!(result.get_area(), 4000.);
+        strips.fill_form(&["100"], "", &[]).unwrap();
+        let result = strips.calculate(1., 1., &NumberFormat::default()).unwrap();
+        assert_eq!(result.get_area(), 0.);
+        assert_eq!(
+            strips.fill_form(&["100", "10"], "", &[]),
+            Err(messages::WRONG_DIMENSIONS)
+        );
+        assert_eq!(
+            strips.fill_form(&["100", "10", "1.5"], "", &[]),
+            Err(messages::PARSE_WRONG)
+        );
+        strips.fill_form(&["100", "10±1", "2"], "", &[]).unwrap();
+        let result = strips.calculate(1., 1., &NumberFormat::default()).unwrap();
+        assert_eq!(result.get_range(), Some((1800., 2200.)));
+    }
 
     #[test]
-    fn test_dot() {
-        let dot: u8 = '.'.try_into().unwrap();
-        assert_eq!(dot, DOT)
+    fn test_fastener() {
+        let mut shapes = get_shapes();
+        let fastener = find_shape(&mut shapes, "fastener").unwrap();
+        assert!(matches!(
+            fastener.form_state()[1],
+            FormElement::Choice(_, names, index) if names[index] == "M10"
+        ));
+        fastener.fill_form(&["50"], "", &["bolt", "m10"]).unwrap();
+        let result = fastener
+            .calculate(1., 1., &NumberFormat::default())
+            .unwrap();
+        assert_eq!(
+            result.get_result(),
+            "Hex bolt M10×50 S=2750.12 (b:26, s:16, h:6.4, k:1)"
+        );
+        assert_eq!(
+            fastener.fill_form(&["50"], "", &["bolt", "m1"]),
+            Err(messages::AMBIGUOUS_OPTION)
+        );
+        fastener.fill_form(&[], "", &["bolt", "m10"]).unwrap();
+        assert!(matches!(
+            fastener.calculate(1., 1., &NumberFormat::default()),
+            Err(messages::MISSING_LENGTH)
+        ));
+        fastener.fill_form(&[], "100", &["washer", "m10"]).unwrap();
+        let pi = std::f64::consts::PI;
+        let washer = pi * (400. - 110.25) / 2. + pi * 30.5 * 2.;
+        let area = fastener
+            .calculate(1., 1., &NumberFormat::default())
+            .unwrap()
+            .get_area();
+        assert!((area - 100. * washer).abs() < 1e-9);
     }
 
     #[test]
-    fn test_comma() {
-        let comma: u8 = ','.try_into().unwrap();
-        assert_eq!(comma, COMMA);
+    fn test_area_range() {
+        let mut shapes = get_shapes();
+        let bushing = find_shape(&mut shapes, "bushing").unwrap();
+        bushing.fill_form(&["20", "10±10", "5"], "", &[]).unwrap();
+        let (min, max) = bushing
+            .calculate(1., 1., &NumberFormat::default())
+            .unwrap()
+            .get_range()
+            .unwrap();
+        assert!(min < max && min.is_finite());
+        let cylinder = find_shape(&mut shapes, "cylinder").unwrap();
+        cylinder
+            .fill_form(&["10", "20", "1.5±0.5"], "", &["threaded"])
+            .unwrap();
+        let (min, max) = cylinder
+            .calculate(1., 1., &NumberFormat::default())
+            .unwrap()
+            .get_range()
+            .unwrap();
+        assert!(min < max);
+    }
+
+    #[test]
+    fn test_steps() {
+        for (key, dimensions, options) in [
+            ("circle", &["10"][..], &[][..]),
+            ("rectangle", &["10", "20"], &[]),
+            ("cylinder", &["10", "20"], &["threaded"]),
+            ("cylinder", &["20", "30", "4"], &["threaded", "trapezoidal"]),
+            ("hexagon", &["10"], &["circumscribed"]),
+            ("hexagon_prism", &["10", "20"], &[]),
+            ("bushing", &["20", "10", "5"], &[]),
+            ("cuboid", &["10", "20", "30"], &[]),
+            ("fastener", &["50"], &["bolt", "M10"]),
+            ("fastener", &[], &["nut", "M8"]),
+            ("fastener", &[], &["washer", "M12"]),
+        ] {
+            let mut shapes = get_shapes();
+            let shape = find_shape(&mut shapes, key).unwrap();
+            shape.fill_form(dimensions, "2", options).unwrap();
+            let result = shape.calculate(1., 1., &NumberFormat::default()).unwrap();
+            let (sum, multiplier) =
+                shape
+                    .get_steps()
+                    .iter()
+                    .fold((0., 1.), |(sum, multiplier), step| match step {
+                        Step::Area(_, area) => (sum + area, multiplier),
+                        Step::Multiplier(_, value) => (sum, multiplier * value),
+                    });
+            assert!(
+                (sum * multiplier - result.get_area()).abs() < 1e-9,
+                "{}",
+                key
+            );
+        }
     }
 }
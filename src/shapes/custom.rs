@@ -0,0 +1,395 @@
+//! Shapes defined by the user in a JSON file:
+//!
+//! ```json
+//! {"shapes": [{
+//!     "key": "bracket",
+//!     "name": "Bracket",
+//!     "fields": [{"name": "a", "label": "Width"}, {"name": "b", "label": "Height"}],
+//!     "options": [{"name": "holes", "label": "With holes"}],
+//!     "area": "2 * a * b - holes * pi * 5^2 / 2",
+//!     "volume": "a * b * 2"
+//! }]}
+//! ```
+//!
+//! Names and labels are shown as written; with the `key:` prefix, e.g.
+//! `"key:thickness"`, they are keys of the translation catalog instead.
+//! Fields are lengths, options are 1 when checked and 0 otherwise, see
+//! [`crate::expression`] for the formulas. Areas are in mm² for lengths in
+//! mm, the factor field is added to every shape.
+
+use std::collections::BTreeSet;
+use std::path::Path;
+use std::sync::{Arc, Mutex, RwLock};
+
+use serde::Deserialize;
+
+use super::shape_list::helpers;
+use super::{FormElement, InnerImplShape, Step};
+use crate::expression::Expression;
+use crate::literals;
+use crate::number_format::{exact, number, NumberFormat};
+
+#[derive(Deserialize)]
+struct ShapeFile {
+    shapes: Vec<ShapeDefinition>,
+}
+
+#[derive(Deserialize)]
+struct ShapeDefinition {
+    key: String,
+    name: String,
+    #[serde(default)]
+    fields: Vec<Variable>,
+    #[serde(default)]
+    options: Vec<Variable>,
+    area: String,
+    volume: Option<String>,
+}
+
+#[derive(Deserialize)]
+struct Variable {
+    name: String,
+    label: String,
+}
+
+/// Parsed definition shared by all copies of a custom shape.
+struct Definition {
+    key: &'static str,
+    name: &'static str,
+    fields: Vec<String>,
+    options: Vec<String>,
+    area: Expression,
+    volume: Option<Expression>,
+    formula: &'static str,
+}
+
+static CUSTOM_SHAPES: RwLock<Vec<CustomShape>> = RwLock::new(Vec::new());
+
+/// Prefix of names and labels that are translation keys.
+const KEY_PREFIX: &str = "key:";
+
+/// Keys, translated labels and formulas of custom shapes, see [`intern`].
+static TEXTS: Mutex<BTreeSet<&'static str>> = Mutex::new(BTreeSet::new());
+
+/// Adds the shapes of a definition file to [`super::get_shapes`]. A missing
+/// file adds nothing. Returns the number of shapes added.
+pub fn load_custom_shapes(path: &Path) -> Result<usize, String> {
+    let source = match std::fs::read_to_string(path) {
+        Ok(source) => source,
+        Err(err) if err.kind() == std::io::ErrorKind::NotFound => return Ok(0),
+        Err(err) => return Err(err.to_string()),
+    };
+    let shapes = parse_custom_shapes(&source)?;
+    let count = shapes.len();
+    if let Ok(mut custom) = CUSTOM_SHAPES.write() {
+        custom.extend(shapes);
+    }
+    Ok(count)
+}
+
+/// Shapes added with [`load_custom_shapes`].
+pub fn custom_shapes() -> Vec<CustomShape> {
+    CUSTOM_SHAPES
+        .read()
+        .map(|custom| custom.clone())
+        .unwrap_or_default()
+}
+
+/// Shapes of a definition file, checked for unknown variables and
+/// duplicate keys.
+fn parse_custom_shapes(source: &str) -> Result<Vec<CustomShape>, String> {
+    let file: ShapeFile = serde_json::from_str(source).map_err(|err| err.to_string())?;
+    let mut keys: Vec<&'static str> = super::get_shapes()
+        .iter()
+        .map(|shape| shape.key())
+        .collect();
+    let mut shapes = Vec::new();
+    for definition in file.shapes {
+        let key = definition.key.clone();
+        let shape = CustomShape::new(definition).map_err(|err| format!("{}: {}", key, err))?;
+        if keys.contains(&shape.definition.key) {
+            return Err(format!("{}: duplicate key", key));
+        }
+        keys.push(shape.definition.key);
+        shapes.push(shape);
+    }
+    Ok(shapes)
+}
+
+/// Static copy of `text` for the form labels. Each distinct text is
+/// allocated once and never freed, so loading a file again reuses the
+/// texts instead of leaking new ones.
+fn intern(text: &str) -> &'static str {
+    let mut texts = TEXTS.lock().unwrap_or_else(|err| err.into_inner());
+    if let Some(text) = texts.get(text) {
+        return text;
+    }
+    let text: &'static str = Box::leak(text.into());
+    texts.insert(text);
+    text
+}
+
+/// Name or label to show: a catalog key when marked with [`KEY_PREFIX`],
+/// otherwise the text itself.
+fn label(text: &str) -> &'static str {
+    match text.strip_prefix(KEY_PREFIX) {
+        Some(key) => intern(key),
+        None => literals::verbatim(text),
+    }
+}
+
+/// Shape from a definition file, see [`load_custom_shapes`].
+#[derive(Clone)]
+pub struct CustomShape {
+    definition: Arc<Definition>,
+    state: Vec<FormElement>,
+    values: Vec<f64>,
+    factor: f64,
+}
+
+impl CustomShape {
+    fn new(definition: ShapeDefinition) -> Result<Self, String> {
+        let variables: Vec<&Variable> = definition
+            .fields
+            .iter()
+            .chain(definition.options.iter())
+            .collect();
+        for (index, variable) in variables.iter().enumerate() {
+            let name = variable.name.as_str();
+            let valid = name.starts_with(|letter: char| letter.is_alphabetic() || letter == '_')
+                && name
+                    .chars()
+                    .all(|letter| letter.is_alphanumeric() || letter == '_');
+            if !valid || name == "pi" || name == "k" {
+                return Err(format!("wrong name `{}`", name));
+            }
+            if variables[..index]
+                .iter()
+                .any(|other| other.name == variable.name)
+            {
+                return Err(format!("duplicate name `{}`", name));
+            }
+        }
+        let area = Expression::parse(&definition.area)?;
+        let volume = definition
+            .volume
+            .as_deref()
+            .map(Expression::parse)
+            .transpose()?;
+        for expression in std::iter::once(&area).chain(volume.iter()) {
+            if let Some(unknown) = expression
+                .variables()
+                .into_iter()
+                .find(|name| variables.iter().all(|variable| variable.name != *name))
+            {
+                return Err(format!("unknown variable `{}`", unknown));
+            }
+        }
+        let mut state: Vec<FormElement> = definition
+            .fields
+            .iter()
+            .map(|field| FormElement::InputField(label(&field.label), String::new()))
+            .collect();
+        state.push(FormElement::FactorField(String::new()));
+        state.extend(
+            definition
+                .options
+                .iter()
+                .map(|option| FormElement::CheckBox(label(&option.label), false)),
+        );
+        let definition = Definition {
+            key: intern(&definition.key),
+            name: label(&definition.name),
+            fields: definition.fields.iter().map(|f| f.name.clone()).collect(),
+            options: definition.options.iter().map(|o| o.name.clone()).collect(),
+            formula: intern(&format!("S = ({})·k", definition.area)),
+            area,
+            volume,
+        };
+        Ok(Self {
+            values: vec![0.; definition.fields.len() + definition.options.len()],
+            definition: Arc::new(definition),
+            state,
+            factor: 1.,
+        })
+    }
+
+    fn variables(&self) -> Vec<(&str, f64)> {
+        self.definition
+            .fields
+            .iter()
+            .chain(self.definition.options.iter())
+            .map(String::as_str)
+            .zip(self.values.iter().copied())
+            .collect()
+    }
+}
+
+impl InnerImplShape for CustomShape {
+    fn parse_input(
+        &mut self,
+        input_factor: f64,
+        format: &NumberFormat,
+    ) -> Result<(), &'static str> {
+        let mut negative = false;
+        let mut lengths = Vec::new();
+        let mut options = Vec::new();
+        for field in self.state.iter() {
+            match field {
+                FormElement::InputField(..) | FormElement::OptionalField(..) => {
+                    lengths.push(helpers::get_lenght(
+                        field,
+                        input_factor,
+                        format,
+                        &mut negative,
+                    )?);
+                }
+                FormElement::CheckBox(..) => {
+                    options.push(if helpers::get_option(field)? { 1. } else { 0. });
+                }
+                _ => {}
+            }
+        }
+        let factor = self
+            .state
+            .iter()
+            .find(|field| matches!(field, FormElement::FactorField(_)))
+            .ok_or(literals::messages::WRONG_FIELD)?;
+        self.factor = helpers::get_factor(factor, format, negative)?;
+        lengths.extend(options);
+        self.values = lengths;
+        Ok(())
+    }
+
+    fn get_area(&self) -> f64 {
+        self.definition.area.evaluate(&self.variables()) * self.factor
+    }
+
+    fn get_volume(&self) -> Option<f64> {
+        self.definition
+            .volume
+            .as_ref()
+            .map(|volume| volume.evaluate(&self.variables()) * self.factor)
+    }
+
+    fn get_result(&self, input_factor: f64, area: f64, format: &NumberFormat) -> String {
+        let mut values: Vec<String> = self
+            .definition
+            .fields
+            .iter()
+            .zip(self.values.iter())
+            .map(|(name, value)| format!("{}:{}", name, exact(value / input_factor, format)))
+            .collect();
+        values.push(format!("k:{}", exact(self.factor, format)));
+        format!(
+            "{} S={} ({})",
+            literals::tr(self.definition.name),
+            number(area, format),
+            values.join(", ")
+        )
+    }
+
+    fn get_formula(&self) -> &'static str {
+        self.definition.formula
+    }
+
+    fn get_steps(&self) -> Vec<Step> {
+        vec![
+            Step::Area(
+                literals::AREA,
+                self.definition.area.evaluate(&self.variables()),
+            ),
+            Step::Multiplier(literals::FACTOR, self.factor),
+        ]
+    }
+
+    fn state(&mut self) -> &mut [FormElement] {
+        &mut self.state
+    }
+
+    fn get_name(&self) -> &'static str {
+        self.definition.name
+    }
+
+    fn get_key(&self) -> &'static str {
+        self.definition.key
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::parse_custom_shapes;
+    use crate::literals;
+    use crate::number_format::NumberFormat;
+    use crate::shapes::{AreaShape, FormElement};
+
+    const BRACKET: &str = r#"{"shapes": [{
+        "key": "bracket",
+        "name": "Bracket",
+        "fields": [{"name": "a", "label": "Width"}, {"name": "b", "label": "Height"}],
+        "options": [{"name": "holes", "label": "With holes"}],
+        "area": "2 * a * b - holes * 10",
+        "volume": "a * b"
+    }]}"#;
+
+    #[test]
+    fn test_custom_shape() {
+        let mut shapes = parse_custom_shapes(BRACKET).unwrap();
+        let bracket = &mut shapes[0];
+        assert_eq!(bracket.key(), "bracket");
+        assert!(matches!(
+            bracket.form_state()[3],
+            FormElement::CheckBox("With holes", false)
+        ));
+        bracket.fill_form(&["10", "20"], "2", &["with"]).unwrap();
+        let result = bracket.calculate(1., 1., &NumberFormat::default()).unwrap();
+        assert_eq!(result.get_area(), 780.);
+        assert_eq!(result.get_volume(), Some(400.));
+        assert_eq!(result.get_result(), "Bracket S=780 (a:10, b:20, k:2)");
+        let again = parse_custom_shapes(BRACKET).unwrap();
+        assert!(std::ptr::eq(again[0].key(), shapes[0].key()));
+    }
+
+    #[test]
+    fn test_custom_labels() {
+        let mut shapes = parse_custom_shapes(
+            r#"{"shapes": [{"key": "tab", "name": "area", "area": "t + a", "fields": [
+                {"name": "t", "label": "thickness"}, {"name": "a", "label": "key:thickness"}
+            ]}]}"#,
+        )
+        .unwrap();
+        assert_eq!(shapes[0].name(), "area");
+        let labels: Vec<&str> = shapes[0]
+            .form_state()
+            .iter()
+            .filter_map(|field| match field {
+                FormElement::InputField(label, _) => Some(literals::tr(label)),
+                _ => None,
+            })
+            .collect();
+        assert_eq!(labels, ["thickness", "Coating thickness, µm"]);
+    }
+
+    #[test]
+    fn test_custom_shape_errors() {
+        let shape = |fields: &str, area: &str| {
+            parse_custom_shapes(&format!(
+                r#"{{"shapes": [{{"key": "part", "name": "Part", "fields": [{}], "area": "{}"}}]}}"#,
+                fields, area
+            ))
+        };
+        assert!(shape(r#"{"name": "a", "label": "A"}"#, "a * a").is_ok());
+        assert!(shape(r#"{"name": "a", "label": "A"}"#, "a * b").is_err());
+        assert!(shape(r#"{"name": "a", "label": "A"}"#, "a *").is_err());
+        assert!(shape(r#"{"name": "pi", "label": "A"}"#, "pi").is_err());
+        assert!(shape(
+            r#"{"name": "a", "label": "A"}, {"name": "a", "label": "B"}"#,
+            "a"
+        )
+        .is_err());
+        assert!(parse_custom_shapes(
+            r#"{"shapes": [{"key": "circle", "name": "C", "area": "1"}]}"#
+        )
+        .is_err());
+    }
+}
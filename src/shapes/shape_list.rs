@@ -1,25 +1,24 @@
-mod helpers;
-mod parser;
+mod fastener;
+pub(super) mod helpers;
 
 use super::FormElement;
 use super::InnerImplShape;
-use crate::literals;
+use super::Step;
+use crate::literals::{self, tr};
+use crate::number_format::{exact, number, NumberFormat};
+use crate::thread::{self, ThreadProfile};
+pub use fastener::AreaFastener;
 
-const CIRCLE: &str = if cfg!(feature = "lang_rus") {
-    "Круг"
-} else {
-    "Circle"
-};
+const BASE_AREA: &str = "base-area";
+const LATERAL_AREA: &str = "lateral-area";
 
-const DIAMETER_CIR: &str = if cfg!(feature = "lang_rus") {
-    "Диаметр"
-} else {
-    "Diameter"
-};
+const CIRCLE: &str = "circle";
+const DIAMETER_CIR: &str = "diameter-cir";
 
+/// Circle by its diameter.
 #[derive(Clone)]
 pub struct AreaCircle {
-    state: [FormElement; 6],
+    state: Vec<FormElement>,
     diameter: f64,
     factor: f64,
 }
@@ -27,13 +26,9 @@ pub struct AreaCircle {
 impl Default for AreaCircle {
     fn default() -> Self {
         Self {
-            state: [
+            state: vec![
                 FormElement::InputField(DIAMETER_CIR, String::new()),
                 FormElement::FactorField(String::new()),
-                FormElement::NoElement,
-                FormElement::NoElement,
-                FormElement::NoElement,
-                FormElement::NoElement,
             ],
             diameter: 0.,
             factor: 1.,
@@ -42,19 +37,26 @@ impl Default for AreaCircle {
 }
 
 impl InnerImplShape for AreaCircle {
-    fn state(&mut self) -> &mut [FormElement; 6] {
-        helpers::std_validate_state(&mut self.state);
+    fn state(&mut self) -> &mut [FormElement] {
         &mut self.state
     }
 
-    fn get_name(&self) -> &str {
+    fn get_name(&self) -> &'static str {
         CIRCLE
     }
 
-    fn parse_input(&mut self, input_factor: f64) -> Result<(), &'static str> {
+    fn get_key(&self) -> &'static str {
+        "circle"
+    }
+
+    fn parse_input(
+        &mut self,
+        input_factor: f64,
+        format: &NumberFormat,
+    ) -> Result<(), &'static str> {
         let mut negative = false;
-        self.diameter = helpers::get_lenght(&self.state[0], input_factor, &mut negative)?;
-        self.factor = helpers::get_factor(&self.state[1], negative)?;
+        self.diameter = helpers::get_lenght(&self.state[0], input_factor, format, &mut negative)?;
+        self.factor = helpers::get_factor(&self.state[1], format, negative)?;
         Ok(())
     }
 
@@ -62,37 +64,36 @@ impl InnerImplShape for AreaCircle {
         self.factor * self.diameter * self.diameter * std::f64::consts::PI / 4.
     }
 
-    fn get_result(&self, input_factor: f64, area: f64) -> String {
-        let d = self.diameter / input_factor;
-        if cfg!(feature = "lang_rus") {
-            format!("Круг S={} (d:{}, k:{})", area, d, self.factor)
-        } else {
-            format!("Circle S={} (d:{}, k:{})", area, d, self.factor)
-        }
+    fn get_result(&self, input_factor: f64, area: f64, format: &NumberFormat) -> String {
+        let area = number(area, format);
+        let k = exact(self.factor, format);
+        let d = exact(self.diameter / input_factor, format);
+        format!("{} S={} (d:{}, k:{})", tr(CIRCLE), area, d, k)
     }
-}
 
-const RECTANGLE: &str = if cfg!(feature = "lang_rus") {
-    "Прямоугольник"
-} else {
-    "Rectangle"
-};
+    fn get_formula(&self) -> &'static str {
+        "S = π·d²/4·k"
+    }
 
-const LENGHT_REC: &str = if cfg!(feature = "lang_rus") {
-    "Длина"
-} else {
-    "Lenght"
-};
+    fn get_steps(&self) -> Vec<Step> {
+        vec![
+            Step::Area(
+                BASE_AREA,
+                self.diameter * self.diameter * std::f64::consts::PI / 4.,
+            ),
+            Step::Multiplier(literals::FACTOR, self.factor),
+        ]
+    }
+}
 
-const HEIGHT_REC: &str = if cfg!(feature = "lang_rus") {
-    "Высота"
-} else {
-    "Height"
-};
+const RECTANGLE: &str = "rectangle";
+const LENGHT_REC: &str = "lenght-rec";
+const HEIGHT_REC: &str = "height-rec";
 
+/// Rectangle by its length and height.
 #[derive(Clone)]
 pub struct AreaRectangle {
-    state: [FormElement; 6],
+    state: Vec<FormElement>,
     lenght: f64,
     height: f64,
     factor: f64,
@@ -101,13 +102,10 @@ pub struct AreaRectangle {
 impl Default for AreaRectangle {
     fn default() -> Self {
         Self {
-            state: [
+            state: vec![
                 FormElement::InputField(LENGHT_REC, String::new()),
                 FormElement::InputField(HEIGHT_REC, String::new()),
                 FormElement::FactorField(String::new()),
-                FormElement::NoElement,
-                FormElement::NoElement,
-                FormElement::NoElement,
             ],
             lenght: 0.,
             height: 0.,
@@ -117,11 +115,15 @@ impl Default for AreaRectangle {
 }
 
 impl InnerImplShape for AreaRectangle {
-    fn parse_input(&mut self, input_factor: f64) -> Result<(), &'static str> {
+    fn parse_input(
+        &mut self,
+        input_factor: f64,
+        format: &NumberFormat,
+    ) -> Result<(), &'static str> {
         let mut negative = false;
-        self.lenght = helpers::get_lenght(&self.state[0], input_factor, &mut negative)?;
-        self.height = helpers::get_lenght(&self.state[1], input_factor, &mut negative)?;
-        self.factor = helpers::get_factor(&self.state[2], negative)?;
+        self.lenght = helpers::get_lenght(&self.state[0], input_factor, format, &mut negative)?;
+        self.height = helpers::get_lenght(&self.state[1], input_factor, format, &mut negative)?;
+        self.factor = helpers::get_factor(&self.state[2], format, negative)?;
         Ok(())
     }
 
@@ -129,91 +131,120 @@ impl InnerImplShape for AreaRectangle {
         self.height * self.lenght * self.factor
     }
 
-    fn state(&mut self) -> &mut [FormElement; 6] {
-        helpers::std_validate_state(&mut self.state);
+    fn state(&mut self) -> &mut [FormElement] {
         &mut self.state
     }
 
-    fn get_name(&self) -> &str {
+    fn get_name(&self) -> &'static str {
         RECTANGLE
     }
-    fn get_result(&self, input_factor: f64, area: f64) -> String {
-        let b = self.height / input_factor;
-        let a = self.lenght / input_factor;
-        if cfg!(feature = "lang_rus") {
-            format!(
-                "Прямоугольник S={} (a:{}, b:{}, k:{})",
-                area, a, b, self.factor
-            )
-        } else {
-            format!("Rectangle S={} (l:{}, h:{}, k:{})", area, a, b, self.factor)
-        }
+
+    fn get_key(&self) -> &'static str {
+        "rectangle"
+    }
+
+    fn get_result(&self, input_factor: f64, area: f64, format: &NumberFormat) -> String {
+        let area = number(area, format);
+        let k = exact(self.factor, format);
+        let b = exact(self.height / input_factor, format);
+        let a = exact(self.lenght / input_factor, format);
+        format!("{} S={} (l:{}, h:{}, k:{})", tr(RECTANGLE), area, a, b, k)
+    }
+
+    fn get_formula(&self) -> &'static str {
+        "S = l·h·k"
+    }
+
+    fn get_steps(&self) -> Vec<Step> {
+        vec![
+            Step::Area(BASE_AREA, self.lenght * self.height),
+            Step::Multiplier(literals::FACTOR, self.factor),
+        ]
     }
 }
 
-const HEIGHT_CYL: &str = if cfg!(feature = "lang_rus") {
-    "Высота"
-} else {
-    "Height"
-};
-
-const DIAMETER_CYL: &str = if cfg!(feature = "lang_rus") {
-    "Диаметр"
-} else {
-    "Diameter"
-};
-
-const CYLINDER: &str = if cfg!(feature = "lang_rus") {
-    "Цилиндр"
-} else {
-    "Cylinder"
-};
-
-const THREADED: &str = if cfg!(feature = "lang_rus") {
-    "Резьба"
-} else {
-    "Threaded"
-};
+const HEIGHT_CYL: &str = "height-cyl";
+const DIAMETER_CYL: &str = "diameter-cyl";
+const CYLINDER: &str = "cylinder";
+const THREADED: &str = "threaded";
+const THREAD_MULTIPLIER: &str = "thread-multiplier";
+const THREAD_PITCH: &str = "thread-pitch";
+const THREAD_PROFILE: &str = "thread-profile";
+const THREAD_PROFILES: &[&str] = &["metric", "trapezoidal"];
 
+/// Side surface of a cylinder, optionally threaded, see [`crate::thread`].
 #[derive(Clone)]
 pub struct AreaCylinder {
-    state: [FormElement; 6],
+    state: Vec<FormElement>,
     diameter: f64,
     height: f64,
     factor: f64,
     threaded: bool,
+    pitch: f64,
+    profile: ThreadProfile,
 }
 
 impl Default for AreaCylinder {
     fn default() -> Self {
         Self {
-            state: [
+            state: vec![
                 FormElement::InputField(DIAMETER_CYL, String::new()),
                 FormElement::InputField(HEIGHT_CYL, String::new()),
                 FormElement::FactorField(String::new()),
                 FormElement::CheckBox(THREADED, false),
-                FormElement::NoElement,
-                FormElement::NoElement,
+                FormElement::OptionalField(THREAD_PITCH, String::new()),
+                FormElement::Choice(THREAD_PROFILE, THREAD_PROFILES, 0),
             ],
             diameter: 0.,
             height: 0.,
             factor: 1.,
             threaded: false,
+            pitch: 0.,
+            profile: ThreadProfile::Metric,
+        }
+    }
+}
+
+impl AreaCylinder {
+    /// Diameter of the surface cylinder and the thread multiplier: the
+    /// pitch diameter and the profile ratio when the pitch is known.
+    fn surface(&self) -> (f64, f64) {
+        if !self.threaded {
+            (self.diameter, 1.)
+        } else if self.pitch > 0. {
+            (
+                self.profile.pitch_diameter(self.diameter, self.pitch),
+                self.profile.surface_ratio(),
+            )
+        } else {
+            (self.diameter, thread::DEFAULT_MULTIPLIER)
         }
     }
 }
 
 impl InnerImplShape for AreaCylinder {
-    fn parse_input(&mut self, input_factor: f64) -> Result<(), &'static str> {
+    fn parse_input(
+        &mut self,
+        input_factor: f64,
+        format: &NumberFormat,
+    ) -> Result<(), &'static str> {
         let mut negative = false;
-        self.diameter = helpers::get_lenght(&self.state[0], input_factor, &mut negative)?;
-        self.height = helpers::get_lenght(&self.state[1], input_factor, &mut negative)?;
-        self.factor = helpers::get_factor(&self.state[2], negative)?;
+        self.diameter = helpers::get_lenght(&self.state[0], input_factor, format, &mut negative)?;
+        self.height = helpers::get_lenght(&self.state[1], input_factor, format, &mut negative)?;
+        self.factor = helpers::get_factor(&self.state[2], format, negative)?;
         self.threaded = helpers::get_option(&self.state[3])?;
+        self.pitch = helpers::get_lenght(&self.state[4], input_factor, format, &mut false)?;
+        self.profile = match helpers::get_choice(&self.state[5])? {
+            0 => ThreadProfile::Metric,
+            _ => ThreadProfile::Trapezoidal,
+        };
+        if self.threaded && self.surface().0 <= 0. {
+            return Err(literals::messages::WRONG_PITCH);
+        }
         Ok(())
     }
 
-    fn get_name(&self) -> &str {
+    fn get_name(&self) -> &'static str {
         if self.threaded {
             THREADED
         } else {
@@ -221,57 +252,75 @@ impl InnerImplShape for AreaCylinder {
         }
     }
 
-    fn state(&mut self) -> &mut [FormElement; 6] {
-        helpers::std_validate_state(&mut self.state);
+    fn get_key(&self) -> &'static str {
+        "cylinder"
+    }
+
+    fn state(&mut self) -> &mut [FormElement] {
         &mut self.state
     }
 
     fn get_area(&self) -> f64 {
-        let mut area = self.diameter * std::f64::consts::PI * self.height * self.factor;
-        if self.threaded {
-            area *= 1.5;
+        let (diameter, multiplier) = self.surface();
+        diameter * std::f64::consts::PI * self.height * multiplier * self.factor
+    }
+
+    fn get_volume(&self) -> Option<f64> {
+        Some(std::f64::consts::PI * self.diameter * self.diameter / 4. * self.height * self.factor)
+    }
+
+    fn get_result(&self, input_factor: f64, area: f64, format: &NumberFormat) -> String {
+        let area = number(area, format);
+        let k = exact(self.factor, format);
+        let d = exact(self.diameter / input_factor, format);
+        let h = exact(self.height / input_factor, format);
+        if self.threaded && self.pitch > 0. {
+            let p = exact(self.pitch / input_factor, format);
+            return format!(
+                "{} S={} (d:{}, h:{}, P:{}, k:{})",
+                tr(THREADED),
+                area,
+                d,
+                h,
+                p,
+                k
+            );
+        }
+        let name = if self.threaded { THREADED } else { CYLINDER };
+        format!("{} S={} (d:{}, h:{}, k:{})", tr(name), area, d, h, k)
+    }
+
+    fn get_formula(&self) -> &'static str {
+        match (self.threaded, self.pitch > 0., self.profile) {
+            (false, _, _) => "S = π·d·h·k",
+            (true, false, _) => "S = π·d·h·t·k",
+            (true, true, ThreadProfile::Metric) => "S = π·d₂·h·t·k, d₂ = d − 3√3/8·P",
+            (true, true, ThreadProfile::Trapezoidal) => "S = π·d₂·h·t·k, d₂ = d − P/2",
         }
-        area
     }
 
-    fn get_result(&self, input_factor: f64, area: f64) -> String {
-        let d = self.diameter / input_factor;
-        let h = self.height / input_factor;
+    fn get_steps(&self) -> Vec<Step> {
+        let (diameter, multiplier) = self.surface();
+        let mut steps = vec![Step::Area(
+            LATERAL_AREA,
+            std::f64::consts::PI * diameter * self.height,
+        )];
         if self.threaded {
-            if cfg!(feature = "lang_rus") {
-                format!("Резьба S={} (d:{}, h:{}, k:{})", area, d, h, self.factor)
-            } else {
-                format!("Threaded S={} (d:{}, h:{}, k:{})", area, d, h, self.factor)
-            }
-        } else if cfg!(feature = "lang_rus") {
-            format!("Цилиндр S={} (d:{}, h:{}, k:{})", area, d, h, self.factor)
-        } else {
-            format!("Cylinder S={} (d:{}, h:{}, k:{})", area, d, h, self.factor)
+            steps.push(Step::Multiplier(THREAD_MULTIPLIER, multiplier));
         }
+        steps.push(Step::Multiplier(literals::FACTOR, self.factor));
+        steps
     }
 }
 
-const DIAMETER_HEX: &str = if cfg!(feature = "lang_rus") {
-    "Диаметр"
-} else {
-    "Diameter"
-};
-
-const CIRCUMSCRIBED: &str = if cfg!(feature = "lang_rus") {
-    "Описанная окружность"
-} else {
-    "Circumscribed circle"
-};
-
-const HEXAGON: &str = if cfg!(feature = "lang_rus") {
-    "Шестиугольник"
-} else {
-    "Hexagon"
-};
+const DIAMETER_HEX: &str = "diameter-hex";
+const CIRCUMSCRIBED: &str = "circumscribed";
+const HEXAGON: &str = "hexagon";
 
+/// Regular hexagon by the diameter of the inscribed or circumscribed circle.
 #[derive(Clone)]
 pub struct AreaHexagon {
-    state: [FormElement; 6],
+    state: Vec<FormElement>,
     diameter: f64,
     factor: f64,
     circumscribed: bool,
@@ -280,13 +329,10 @@ pub struct AreaHexagon {
 impl Default for AreaHexagon {
     fn default() -> Self {
         Self {
-            state: [
+            state: vec![
                 FormElement::InputField(DIAMETER_HEX, String::new()),
                 FormElement::FactorField(String::new()),
                 FormElement::CheckBox(CIRCUMSCRIBED, false),
-                FormElement::NoElement,
-                FormElement::NoElement,
-                FormElement::NoElement,
             ],
             diameter: 0.,
             factor: 1.,
@@ -296,19 +342,26 @@ impl Default for AreaHexagon {
 }
 
 impl InnerImplShape for AreaHexagon {
-    fn state(&mut self) -> &mut [FormElement; 6] {
-        helpers::std_validate_state(&mut self.state);
+    fn state(&mut self) -> &mut [FormElement] {
         &mut self.state
     }
 
-    fn get_name(&self) -> &str {
+    fn get_name(&self) -> &'static str {
         HEXAGON
     }
 
-    fn parse_input(&mut self, input_factor: f64) -> Result<(), &'static str> {
+    fn get_key(&self) -> &'static str {
+        "hexagon"
+    }
+
+    fn parse_input(
+        &mut self,
+        input_factor: f64,
+        format: &NumberFormat,
+    ) -> Result<(), &'static str> {
         let mut negative = false;
-        self.diameter = helpers::get_lenght(&self.state[0], input_factor, &mut negative)?;
-        self.factor = helpers::get_factor(&self.state[1], negative)?;
+        self.diameter = helpers::get_lenght(&self.state[0], input_factor, format, &mut negative)?;
+        self.factor = helpers::get_factor(&self.state[1], format, negative)?;
         self.circumscribed = helpers::get_option(&self.state[2])?;
         Ok(())
     }
@@ -321,39 +374,45 @@ impl InnerImplShape for AreaHexagon {
         }
     }
 
-    fn get_result(&self, input_factor: f64, area: f64) -> String {
-        let d = self.diameter / input_factor;
+    fn get_result(&self, input_factor: f64, area: f64, format: &NumberFormat) -> String {
+        let area = number(area, format);
+        let k = exact(self.factor, format);
+        let d = exact(self.diameter / input_factor, format);
         if self.circumscribed {
-            if cfg!(feature = "lang_rus") {
-                format!("Шестиугольник S={} (D:{}, k:{})", area, d, self.factor)
-            } else {
-                format!("Hexagon S={} (D:{}, k:{})", area, d, self.factor)
-            }
+            format!("{} S={} (D:{}, k:{})", tr(HEXAGON), area, d, k)
         } else {
-            if cfg!(feature = "lang_rus") {
-                format!("Шестиугольник s={} (d:{}, k:{})", area, d, self.factor)
-            } else {
-                format!("Hexagon s={} (d:{}, k:{})", area, d, self.factor)
-            }
+            format!("{} s={} (d:{}, k:{})", tr(HEXAGON), area, d, k)
         }
     }
-}
 
-const HEX_PRISM: &str = if cfg!(feature = "lang_rus") {
-    "Шестиугольная призма"
-} else {
-    "Hexagon prism"
-};
+    fn get_formula(&self) -> &'static str {
+        if self.circumscribed {
+            "S = 3√3/8·D²·k"
+        } else {
+            "S = √3/2·d²·k"
+        }
+    }
+
+    fn get_steps(&self) -> Vec<Step> {
+        let base = if self.circumscribed {
+            3. * f64::sqrt(3.) / 2. * self.diameter * self.diameter / 4.
+        } else {
+            2. * f64::sqrt(3.) * self.diameter * self.diameter / 4.
+        };
+        vec![
+            Step::Area(BASE_AREA, base),
+            Step::Multiplier(literals::FACTOR, self.factor),
+        ]
+    }
+}
 
-const HEX_PRISM_HEIGHT: &str = if cfg!(feature = "lang_rus") {
-    "Высота"
-} else {
-    "Height"
-};
+const HEX_PRISM: &str = "hex-prism";
+const HEX_PRISM_HEIGHT: &str = "hex-prism-height";
 
+/// Side surface of a hexagonal prism.
 #[derive(Clone)]
 pub struct AreaHexagonPrism {
-    state: [FormElement; 6],
+    state: Vec<FormElement>,
     diameter: f64,
     height: f64,
     factor: f64,
@@ -363,13 +422,11 @@ pub struct AreaHexagonPrism {
 impl Default for AreaHexagonPrism {
     fn default() -> Self {
         Self {
-            state: [
+            state: vec![
                 FormElement::InputField(DIAMETER_HEX, String::new()),
                 FormElement::InputField(HEX_PRISM_HEIGHT, String::new()),
                 FormElement::FactorField(String::new()),
                 FormElement::CheckBox(CIRCUMSCRIBED, false),
-                FormElement::NoElement,
-                FormElement::NoElement,
             ],
             diameter: 0.,
             height: 0.,
@@ -379,20 +436,27 @@ impl Default for AreaHexagonPrism {
     }
 }
 impl InnerImplShape for AreaHexagonPrism {
-    fn state(&mut self) -> &mut [FormElement; 6] {
-        helpers::std_validate_state(&mut self.state);
+    fn state(&mut self) -> &mut [FormElement] {
         &mut self.state
     }
 
-    fn get_name(&self) -> &str {
+    fn get_name(&self) -> &'static str {
         HEX_PRISM
     }
 
-    fn parse_input(&mut self, input_factor: f64) -> Result<(), &'static str> {
+    fn get_key(&self) -> &'static str {
+        "hexagon_prism"
+    }
+
+    fn parse_input(
+        &mut self,
+        input_factor: f64,
+        format: &NumberFormat,
+    ) -> Result<(), &'static str> {
         let mut negative = false;
-        self.diameter = helpers::get_lenght(&self.state[0], input_factor, &mut negative)?;
-        self.height = helpers::get_lenght(&self.state[1], input_factor, &mut negative)?;
-        self.factor = helpers::get_factor(&self.state[2], negative)?;
+        self.diameter = helpers::get_lenght(&self.state[0], input_factor, format, &mut negative)?;
+        self.height = helpers::get_lenght(&self.state[1], input_factor, format, &mut negative)?;
+        self.factor = helpers::get_factor(&self.state[2], format, negative)?;
         self.circumscribed = helpers::get_option(&self.state[3])?;
         Ok(())
     }
@@ -405,64 +469,65 @@ impl InnerImplShape for AreaHexagonPrism {
         }
     }
 
-    fn get_result(&self, input_factor: f64, area: f64) -> String {
-        let h = self.height / input_factor;
-        let d = self.diameter / input_factor;
+    fn get_volume(&self) -> Option<f64> {
+        let base = if self.circumscribed {
+            3. * f64::sqrt(3.) / 2. * self.diameter * self.diameter / 4.
+        } else {
+            2. * f64::sqrt(3.) * self.diameter * self.diameter / 4.
+        };
+        Some(base * self.height * self.factor)
+    }
+
+    fn get_result(&self, input_factor: f64, area: f64, format: &NumberFormat) -> String {
+        let area = number(area, format);
+        let k = exact(self.factor, format);
+        let h = exact(self.height / input_factor, format);
+        let d = exact(self.diameter / input_factor, format);
+        let d_name = if self.circumscribed { "D" } else { "d" };
+        format!(
+            "{} S={} ({}:{}, h:{}, k:{})",
+            tr(HEX_PRISM),
+            area,
+            d_name,
+            d,
+            h,
+            k
+        )
+    }
+
+    fn get_formula(&self) -> &'static str {
         if self.circumscribed {
-            if cfg!(feature = "lang_rus") {
-                format!(
-                    "Призма (N=6) S={} (D:{}, h:{}, k:{})",
-                    area, d, h, self.factor
-                )
-            } else {
-                format!(
-                    "Hexagon prism S={} (D:{}, h:{}, k:{})",
-                    area, d, h, self.factor
-                )
-            }
+            "S = 3·D·h·k"
         } else {
-            if cfg!(feature = "lang_rus") {
-                format!(
-                    "Призма (N=6) S={} (d:{}, h:{}, k:{})",
-                    area, d, h, self.factor
-                )
-            } else {
-                format!(
-                    "Hexagon prism S={} (d:{}, h:{}, k:{})",
-                    area, d, h, self.factor
-                )
-            }
+            "S = 2√3·d·h·k"
         }
     }
+
+    fn get_steps(&self) -> Vec<Step> {
+        let lateral = if self.circumscribed {
+            6. * self.diameter / 2. * self.height
+        } else {
+            6. * self.diameter * 2. / f64::sqrt(3.) / 2. * self.height
+        };
+        vec![
+            Step::Area(LATERAL_AREA, lateral),
+            Step::Multiplier(literals::FACTOR, self.factor),
+        ]
+    }
 }
 
-const BUSHING: &str = if cfg!(feature = "lang_rus") {
-    "Втулка"
-} else {
-    "Bushing"
-};
-
-const BUSHING_HEIGHT: &str = if cfg!(feature = "lang_rus") {
-    "Высота"
-} else {
-    "Height"
-};
-
-const BUSHING_DIAMETER: &str = if cfg!(feature = "lang_rus") {
-    "Диаметр втулки"
-} else {
-    "Outer diameter"
-};
-
-const BUSHING_INNER_DIAMETER: &str = if cfg!(feature = "lang_rus") {
-    "Диаметр отверстия"
-} else {
-    "Inner diameter"
-};
+const BUSHING: &str = "bushing";
+const BUSHING_HEIGHT: &str = "bushing-height";
+const BUSHING_DIAMETER: &str = "bushing-diameter";
+const BUSHING_INNER_DIAMETER: &str = "bushing-inner-diameter";
+const OUTER_SURFACE: &str = "outer-surface";
+const INNER_SURFACE: &str = "inner-surface";
+const END_FACE: &str = "end-face";
 
+/// Outer, inner and end surface of a bushing.
 #[derive(Clone)]
 pub struct AreaBushing {
-    state: [FormElement; 6],
+    state: Vec<FormElement>,
     diameter: f64,
     inner_diameter: f64,
     height: f64,
@@ -472,13 +537,11 @@ pub struct AreaBushing {
 impl Default for AreaBushing {
     fn default() -> Self {
         Self {
-            state: [
+            state: vec![
                 FormElement::InputField(BUSHING_DIAMETER, String::new()),
                 FormElement::InputField(BUSHING_INNER_DIAMETER, String::new()),
                 FormElement::InputField(BUSHING_HEIGHT, String::new()),
                 FormElement::FactorField(String::new()),
-                FormElement::NoElement,
-                FormElement::NoElement,
             ],
             diameter: 0.,
             inner_diameter: 0.,
@@ -489,21 +552,29 @@ impl Default for AreaBushing {
 }
 
 impl InnerImplShape for AreaBushing {
-    fn state(&mut self) -> &mut [FormElement; 6] {
-        helpers::std_validate_state(&mut self.state);
+    fn state(&mut self) -> &mut [FormElement] {
         &mut self.state
     }
 
-    fn get_name(&self) -> &str {
+    fn get_name(&self) -> &'static str {
         BUSHING
     }
 
-    fn parse_input(&mut self, input_factor: f64) -> Result<(), &'static str> {
+    fn get_key(&self) -> &'static str {
+        "bushing"
+    }
+
+    fn parse_input(
+        &mut self,
+        input_factor: f64,
+        format: &NumberFormat,
+    ) -> Result<(), &'static str> {
         let mut negative = false;
-        self.diameter = helpers::get_lenght(&self.state[0], input_factor, &mut negative)?;
-        self.inner_diameter = helpers::get_lenght(&self.state[1], input_factor, &mut negative)?;
-        self.height = helpers::get_lenght(&self.state[2], input_factor, &mut negative)?;
-        self.factor = helpers::get_factor(&self.state[3], negative)?;
+        self.diameter = helpers::get_lenght(&self.state[0], input_factor, format, &mut negative)?;
+        self.inner_diameter =
+            helpers::get_lenght(&self.state[1], input_factor, format, &mut negative)?;
+        self.height = helpers::get_lenght(&self.state[2], input_factor, format, &mut negative)?;
+        self.factor = helpers::get_factor(&self.state[3], format, negative)?;
         if self.diameter <= self.inner_diameter {
             return Err(literals::messages::WRONG_BUSHING);
         }
@@ -517,51 +588,70 @@ impl InnerImplShape for AreaBushing {
             * self.factor
     }
 
-    fn get_result(&self, input_factor: f64, area: f64) -> String {
-        let d1 = self.diameter / input_factor;
-        let d2 = self.inner_diameter / input_factor;
-        let h = self.height / input_factor;
-        if cfg!(feature = "lang_rus") {
-            format!(
-                "Втулка S={} (D:{}, d:{}, h:{}, k:{})",
-                area, d1, d2, h, self.factor
-            )
-        } else {
-            format!(
-                "Bushing S={} (D:{}, d:{}, h:{}, k:{})",
-                area, d1, d2, h, self.factor
-            )
-        }
+    fn get_volume(&self) -> Option<f64> {
+        Some(
+            std::f64::consts::PI
+                * (self.diameter * self.diameter - self.inner_diameter * self.inner_diameter)
+                / 4.0
+                * self.height
+                * self.factor,
+        )
+    }
+
+    fn get_result(&self, input_factor: f64, area: f64, format: &NumberFormat) -> String {
+        let area = number(area, format);
+        let k = exact(self.factor, format);
+        let d1 = exact(self.diameter / input_factor, format);
+        let d2 = exact(self.inner_diameter / input_factor, format);
+        let h = exact(self.height / input_factor, format);
+        format!(
+            "{} S={} (D:{}, d:{}, h:{}, k:{})",
+            tr(BUSHING),
+            area,
+            d1,
+            d2,
+            h,
+            k
+        )
+    }
+
+    fn get_formula(&self) -> &'static str {
+        "S = (π·D·h + π·d·h + π·(D²−d²)/4)·k"
+    }
+
+    fn get_steps(&self) -> Vec<Step> {
+        vec![
+            Step::Area(
+                OUTER_SURFACE,
+                std::f64::consts::PI * self.diameter * self.height,
+            ),
+            Step::Area(
+                INNER_SURFACE,
+                std::f64::consts::PI * self.inner_diameter * self.height,
+            ),
+            Step::Area(
+                END_FACE,
+                std::f64::consts::PI
+                    * (self.diameter * self.diameter - self.inner_diameter * self.inner_diameter)
+                    / 4.0,
+            ),
+            Step::Multiplier(literals::FACTOR, self.factor),
+        ]
     }
 }
 
-const CUBOID: &str = if cfg!(feature = "lang_rus") {
-    "Параллелепипед"
-} else {
-    "Cuboid"
-};
-
-const CUBOID_HEIGHT: &str = if cfg!(feature = "lang_rus") {
-    "Высота"
-} else {
-    "Height"
-};
-
-const CUBOID_BREADTH: &str = if cfg!(feature = "lang_rus") {
-    "Глубина"
-} else {
-    "Breadth"
-};
-
-const CUBOID_WIDTH: &str = if cfg!(feature = "lang_rus") {
-    "Ширина"
-} else {
-    "Width"
-};
+const CUBOID: &str = "cuboid";
+const CUBOID_HEIGHT: &str = "cuboid-height";
+const CUBOID_BREADTH: &str = "cuboid-breadth";
+const CUBOID_WIDTH: &str = "cuboid-width";
+const FACES_AB: &str = "faces-ab";
+const FACES_AC: &str = "faces-ac";
+const FACES_BC: &str = "faces-bc";
 
+/// Full surface of a cuboid.
 #[derive(Clone)]
 pub struct AreaCuboid {
-    state: [FormElement; 6],
+    state: Vec<FormElement>,
     breadth: f64,
     height: f64,
     width: f64,
@@ -571,13 +661,11 @@ pub struct AreaCuboid {
 impl Default for AreaCuboid {
     fn default() -> Self {
         Self {
-            state: [
+            state: vec![
                 FormElement::InputField(CUBOID_HEIGHT, String::new()),
                 FormElement::InputField(CUBOID_BREADTH, String::new()),
                 FormElement::InputField(CUBOID_WIDTH, String::new()),
                 FormElement::FactorField(String::new()),
-                FormElement::NoElement,
-                FormElement::NoElement,
             ],
             breadth: 0.,
             height: 0.,
@@ -588,21 +676,28 @@ impl Default for AreaCuboid {
 }
 
 impl InnerImplShape for AreaCuboid {
-    fn state(&mut self) -> &mut [FormElement; 6] {
-        helpers::std_validate_state(&mut self.state);
+    fn state(&mut self) -> &mut [FormElement] {
         &mut self.state
     }
 
-    fn get_name(&self) -> &str {
+    fn get_name(&self) -> &'static str {
         CUBOID
     }
 
-    fn parse_input(&mut self, input_factor: f64) -> Result<(), &'static str> {
+    fn get_key(&self) -> &'static str {
+        "cuboid"
+    }
+
+    fn parse_input(
+        &mut self,
+        input_factor: f64,
+        format: &NumberFormat,
+    ) -> Result<(), &'static str> {
         let mut negative = false;
-        self.height = helpers::get_lenght(&self.state[0], input_factor, &mut negative)?;
-        self.breadth = helpers::get_lenght(&self.state[1], input_factor, &mut negative)?;
-        self.width = helpers::get_lenght(&self.state[2], input_factor, &mut negative)?;
-        self.factor = helpers::get_factor(&self.state[3], negative)?;
+        self.height = helpers::get_lenght(&self.state[0], input_factor, format, &mut negative)?;
+        self.breadth = helpers::get_lenght(&self.state[1], input_factor, format, &mut negative)?;
+        self.width = helpers::get_lenght(&self.state[2], input_factor, format, &mut negative)?;
+        self.factor = helpers::get_factor(&self.state[3], format, negative)?;
         Ok(())
     }
     fn get_area(&self) -> f64 {
@@ -612,20 +707,37 @@ impl InnerImplShape for AreaCuboid {
         * self.factor
     }
 
-    fn get_result(&self, input_factor: f64, area: f64) -> String {
-        let a = self.height / input_factor;
-        let b = self.breadth / input_factor;
-        let c = self.width / input_factor;
-        if cfg!(feature = "lang_rus") {
-            format!(
-                "Параллелепипед S={} (a:{}, b:{}, c:{}, k:{})",
-                area, a, b, c, self.factor
-            )
-        } else {
-            format!(
-                "Cuboid S={} (a:{}, b:{}, c:{}, k:{})",
-                area, a, b, c, self.factor
-            )
-        }
+    fn get_volume(&self) -> Option<f64> {
+        Some(self.width * self.breadth * self.height * self.factor)
+    }
+
+    fn get_result(&self, input_factor: f64, area: f64, format: &NumberFormat) -> String {
+        let area = number(area, format);
+        let k = exact(self.factor, format);
+        let a = exact(self.height / input_factor, format);
+        let b = exact(self.breadth / input_factor, format);
+        let c = exact(self.width / input_factor, format);
+        format!(
+            "{} S={} (a:{}, b:{}, c:{}, k:{})",
+            tr(CUBOID),
+            area,
+            a,
+            b,
+            c,
+            k
+        )
+    }
+
+    fn get_formula(&self) -> &'static str {
+        "S = 2·(a·b + a·c + b·c)·k"
+    }
+
+    fn get_steps(&self) -> Vec<Step> {
+        vec![
+            Step::Area(FACES_AB, 2.0 * self.height * self.breadth),
+            Step::Area(FACES_AC, 2.0 * self.height * self.width),
+            Step::Area(FACES_BC, 2.0 * self.breadth * self.width),
+            Step::Multiplier(literals::FACTOR, self.factor),
+        ]
     }
 }
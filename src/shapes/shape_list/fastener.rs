@@ -0,0 +1,264 @@
+use super::{
+    exact, helpers, number, tr, AreaCircle, AreaCylinder, AreaHexagon, AreaHexagonPrism,
+    FormElement, InnerImplShape, NumberFormat, Step,
+};
+use crate::fasteners::{self, MetricSize};
+use crate::literals;
+use crate::thread::ThreadProfile;
+
+const FASTENER: &str = "fastener";
+const FASTENER_KIND: &str = "fastener-kind";
+const FASTENER_SIZE: &str = "fastener-size";
+const BOLT_LENGTH: &str = "bolt-length";
+const HEX_BOLT: &str = "bolt";
+const HEX_NUT: &str = "nut";
+const WASHER: &str = "washer";
+const KINDS: &[&str] = &[HEX_BOLT, HEX_NUT, WASHER];
+const BOLT_HEAD: &str = "bolt-head";
+const BOLT_SHANK: &str = "bolt-shank";
+const BOLT_THREAD: &str = "bolt-thread";
+const NUT_FACES: &str = "nut-faces";
+const WASHER_FACES: &str = "washer-faces";
+const WASHER_EDGES: &str = "washer-edges";
+
+#[derive(Clone, Copy, PartialEq)]
+enum Kind {
+    Bolt,
+    Nut,
+    Washer,
+}
+
+/// Standard fastener from [`crate::fasteners`], its surfaces calculated
+/// with the formulas of the basic shapes.
+#[derive(Clone)]
+pub struct AreaFastener {
+    state: Vec<FormElement>,
+    kind: Kind,
+    size: &'static MetricSize,
+    length: f64,
+    factor: f64,
+}
+
+impl Default for AreaFastener {
+    fn default() -> Self {
+        Self {
+            state: vec![
+                FormElement::Choice(FASTENER_KIND, KINDS, 0),
+                FormElement::Choice(FASTENER_SIZE, &fasteners::NAMES, fasteners::default_index()),
+                FormElement::OptionalField(BOLT_LENGTH, String::new()),
+                FormElement::FactorField(String::new()),
+            ],
+            kind: Kind::Bolt,
+            size: &fasteners::SIZES[fasteners::default_index()],
+            length: 0.,
+            factor: 1.,
+        }
+    }
+}
+
+fn circle(diameter: f64) -> f64 {
+    AreaCircle {
+        diameter,
+        ..Default::default()
+    }
+    .get_area()
+}
+
+fn cylinder(diameter: f64, height: f64, pitch: Option<f64>) -> AreaCylinder {
+    AreaCylinder {
+        diameter,
+        height,
+        threaded: pitch.is_some(),
+        pitch: pitch.unwrap_or(0.),
+        profile: ThreadProfile::Metric,
+        ..Default::default()
+    }
+}
+
+/// Hexagon prism of the head or the nut, across flats.
+fn prism(size: &MetricSize, height: f64) -> AreaHexagonPrism {
+    AreaHexagonPrism {
+        diameter: size.width_across_flats,
+        height,
+        ..Default::default()
+    }
+}
+
+fn hexagon(size: &MetricSize) -> f64 {
+    AreaHexagon {
+        diameter: size.width_across_flats,
+        ..Default::default()
+    }
+    .get_area()
+}
+
+impl AreaFastener {
+    fn thread_length(&self) -> f64 {
+        fasteners::thread_length(self.size.diameter, self.length)
+    }
+
+    /// Surfaces without the factor, labelled for [`InnerImplShape::get_steps`].
+    fn surfaces(&self) -> Vec<(&'static str, f64)> {
+        let size = self.size;
+        let d = size.diameter;
+        match self.kind {
+            Kind::Bolt => {
+                let thread = self.thread_length();
+                vec![
+                    (
+                        BOLT_HEAD,
+                        prism(size, size.head_height).get_area() + 2. * hexagon(size) - circle(d),
+                    ),
+                    (
+                        BOLT_SHANK,
+                        cylinder(d, self.length - thread, None).get_area(),
+                    ),
+                    (
+                        BOLT_THREAD,
+                        cylinder(d, thread, Some(size.pitch)).get_area() + circle(d),
+                    ),
+                ]
+            }
+            Kind::Nut => vec![
+                (
+                    NUT_FACES,
+                    prism(size, size.nut_height).get_area() + 2. * (hexagon(size) - circle(d)),
+                ),
+                (
+                    BOLT_THREAD,
+                    cylinder(d, size.nut_height, Some(size.pitch)).get_area(),
+                ),
+            ],
+            Kind::Washer => {
+                let (inner, outer) = (size.washer_inner, size.washer_outer);
+                vec![
+                    (WASHER_FACES, 2. * (circle(outer) - circle(inner))),
+                    (
+                        WASHER_EDGES,
+                        cylinder(outer, size.washer_thickness, None).get_area()
+                            + cylinder(inner, size.washer_thickness, None).get_area(),
+                    ),
+                ]
+            }
+        }
+    }
+}
+
+impl InnerImplShape for AreaFastener {
+    fn state(&mut self) -> &mut [FormElement] {
+        &mut self.state
+    }
+
+    fn get_name(&self) -> &'static str {
+        FASTENER
+    }
+
+    fn get_key(&self) -> &'static str {
+        "fastener"
+    }
+
+    fn parse_input(
+        &mut self,
+        input_factor: f64,
+        format: &NumberFormat,
+    ) -> Result<(), &'static str> {
+        self.kind = match helpers::get_choice(&self.state[0])? {
+            0 => Kind::Bolt,
+            1 => Kind::Nut,
+            _ => Kind::Washer,
+        };
+        self.size = fasteners::SIZES
+            .get(helpers::get_choice(&self.state[1])?)
+            .ok_or(literals::messages::WRONG_FIELD)?;
+        let mut negative = false;
+        self.length = helpers::get_lenght(&self.state[2], input_factor, format, &mut negative)?;
+        self.factor = helpers::get_factor(&self.state[3], format, negative)?;
+        if self.kind == Kind::Bolt && self.length <= 0. {
+            return Err(literals::messages::MISSING_LENGTH);
+        }
+        Ok(())
+    }
+
+    fn get_area(&self) -> f64 {
+        self.surfaces()
+            .iter()
+            .fold(0., |area, (_, surface)| area + surface)
+            * self.factor
+    }
+
+    fn get_volume(&self) -> Option<f64> {
+        let size = self.size;
+        let volume = match self.kind {
+            Kind::Bolt => {
+                prism(size, size.head_height).get_volume()?
+                    + cylinder(size.diameter, self.length, None).get_volume()?
+            }
+            Kind::Nut => {
+                prism(size, size.nut_height).get_volume()?
+                    - cylinder(size.diameter, size.nut_height, None).get_volume()?
+            }
+            Kind::Washer => {
+                cylinder(size.washer_outer, size.washer_thickness, None).get_volume()?
+                    - cylinder(size.washer_inner, size.washer_thickness, None).get_volume()?
+            }
+        };
+        Some(volume * self.factor)
+    }
+
+    fn get_result(&self, input_factor: f64, area: f64, format: &NumberFormat) -> String {
+        let area = number(area, format);
+        let k = exact(self.factor, format);
+        let size = self.size;
+        let length = |value: f64| exact(value / input_factor, format);
+        match self.kind {
+            Kind::Bolt => format!(
+                "{} {}×{} S={} (b:{}, s:{}, h:{}, k:{})",
+                tr(HEX_BOLT),
+                size.name,
+                length(self.length),
+                area,
+                length(self.thread_length()),
+                length(size.width_across_flats),
+                length(size.head_height),
+                k
+            ),
+            Kind::Nut => format!(
+                "{} {} S={} (s:{}, m:{}, k:{})",
+                tr(HEX_NUT),
+                size.name,
+                area,
+                length(size.width_across_flats),
+                length(size.nut_height),
+                k
+            ),
+            Kind::Washer => format!(
+                "{} {} S={} (D:{}, d:{}, h:{}, k:{})",
+                tr(WASHER),
+                size.name,
+                area,
+                length(size.washer_outer),
+                length(size.washer_inner),
+                length(size.washer_thickness),
+                k
+            ),
+        }
+    }
+
+    fn get_formula(&self) -> &'static str {
+        match self.kind {
+            Kind::Bolt => "S = (2√3·s·h + √3·s² + π·d·(l − b) + π·d₂·b·t)·k",
+            Kind::Nut => "S = (2√3·s·m + √3·s² − π·d²/2 + π·d₂·m·t)·k",
+            Kind::Washer => "S = (π·(D² − d²)/2 + π·(D + d)·h)·k",
+        }
+    }
+
+    fn get_steps(&self) -> Vec<Step> {
+        let mut steps: Vec<Step> = self
+            .surfaces()
+            .into_iter()
+            .map(|(label, area)| Step::Area(label, area))
+            .collect();
+        steps.push(Step::Multiplier(literals::FACTOR, self.factor));
+        steps
+    }
+}
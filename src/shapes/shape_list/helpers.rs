@@ -1,15 +1,19 @@
 use crate::literals::messages;
+use crate::number_format::NumberFormat;
+use crate::parser::{decimal_separator, is_sign, split_numbers, split_range, TOLERANCE};
 
-use super::parser::{decimal_separator, is_sign, split_numbers};
 use super::FormElement;
 
 pub fn get_lenght(
     field: &FormElement,
     factor: f64,
+    format: &NumberFormat,
     negative: &mut bool,
 ) -> Result<f64, &'static str> {
     let x = match field {
-        FormElement::InputField(_, x) => split_numbers(x.as_str()),
+        FormElement::InputField(_, x) | FormElement::OptionalField(_, x) => {
+            split_range(x.as_str(), format).map(|range| range.nominal)
+        }
         _ => return Err(messages::WRONG_FIELD),
     };
     x.map(|number| {
@@ -22,13 +26,18 @@ pub fn get_lenght(
     })
 }
 
-pub fn get_factor(field: &FormElement, negative: bool) -> Result<f64, &'static str> {
+#[allow(clippy::neg_multiply)]
+pub fn get_factor(
+    field: &FormElement,
+    format: &NumberFormat,
+    negative: bool,
+) -> Result<f64, &'static str> {
     match field {
         FormElement::FactorField(x) => {
             let tmp = if x.is_empty() {
                 Ok(1.0)
             } else {
-                split_numbers(x.as_str())
+                split_numbers(x.as_str(), format)
             };
             tmp.map(|x| {
                 if negative && x.is_sign_positive() {
@@ -49,36 +58,76 @@ pub fn get_option(field: &FormElement) -> Result<bool, &'static str> {
     }
 }
 
-pub fn std_validate_state(state: &mut [FormElement; 6]) {
+/// Index of the selected option of a choice.
+pub fn get_choice(field: &FormElement) -> Result<usize, &'static str> {
+    match field {
+        FormElement::Choice(_, _, selected) => Ok(*selected),
+        _ => Err(messages::WRONG_FIELD),
+    }
+}
+
+/// Removes from the text fields what cannot be part of a number, the
+/// grouping separator of `format` is kept between digits. A second decimal
+/// separator in a number is dropped unless it can be a `.` or `,` grouping.
+pub fn std_validate_state(state: &mut [FormElement], format: &NumberFormat) {
     for field in state {
         match field {
-            FormElement::InputField(_, input) | FormElement::FactorField(input) => {
-                validate(input);
+            FormElement::InputField(_, input)
+            | FormElement::OptionalField(_, input)
+            | FormElement::FactorField(input) => {
+                validate(input, format);
+            }
+            FormElement::Group(_, _, entries) => {
+                for entry in entries {
+                    std_validate_state(entry, format);
+                }
             }
             _ => {}
         }
     }
 }
 
-fn validate(input: &mut String) {
+fn validate(input: &mut String, format: &NumberFormat) {
+    let grouping = format.grouping_separator();
+    // With "." or "," grouping "1.234.567" is valid, so repeated separators
+    // are left for the parser to sort out.
+    let repeats = matches!(grouping, Some('.' | ','));
     let mut has_decimator = false;
-    let mut has_digits = false;
     let mut has_sign = false;
+    // One range per field: "49.8..50.2".
+    let mut has_range = false;
+    let mut last = ' ';
+    let chars: Vec<char> = input.chars().collect();
+    let mut index = 0;
     input.retain(|letter| {
-        if letter.is_ascii_digit() {
+        index += 1;
+        let keep = if letter.is_ascii_digit() {
             has_sign = false;
-            has_digits = true;
+            true
+        } else if Some(letter) == grouping && last.is_ascii_digit() {
+            // "1'234" or "1 234"
             true
         } else if decimal_separator(letter) {
-            if !has_decimator {
+            if repeats || !has_decimator {
                 has_decimator = true;
                 true
+            } else if letter == '.' && !has_range && last.is_ascii_digit() {
+                // Start of a range, kept while it is typed: "49.8." or "49.8.."
+                !matches!(chars.get(index), Some(next) if *next != '.')
+            } else if letter == '.' && !has_range && last == '.' {
+                has_range = true;
+                has_decimator = false;
+                true
             } else {
                 false
             }
+        } else if letter == TOLERANCE {
+            // A second tolerance as in "50±0,2±1" is left for the parser.
+            has_decimator = false;
+            has_sign = false;
+            true
         } else if letter == ' ' {
             has_decimator = false;
-            has_digits = false;
             true
         } else if is_sign(letter) {
             if has_sign {
@@ -90,13 +139,23 @@ fn validate(input: &mut String) {
             }
         } else {
             false
+        };
+        if keep {
+            last = letter;
         }
+        keep
     })
 }
 
 #[cfg(test)]
 mod tests {
-    use super::validate;
+    use crate::literals::messages;
+    use crate::number_format::{DecimalSeparator, Grouping, NumberFormat};
+    use crate::parser::{split_numbers, split_range};
+
+    fn validate(input: &mut String) {
+        super::validate(input, &NumberFormat::default());
+    }
 
     #[test]
     fn test_validator_1() {
@@ -129,4 +188,96 @@ mod tests {
         validate(&mut input);
         assert_eq!(String::from("-4,"), input);
     }
+
+    #[test]
+    fn test_validator_range() {
+        let mut input = String::from("49.8..50.2.");
+        validate(&mut input);
+        assert_eq!(String::from("49.8..50.2"), input);
+        let mut input = String::from("49.8.");
+        validate(&mut input);
+        assert_eq!(String::from("49.8."), input);
+        let mut input = String::from("12.5.3");
+        validate(&mut input);
+        assert_eq!(String::from("12.53"), input);
+        let mut input = String::from("50±0,2±1");
+        validate(&mut input);
+        assert_eq!(String::from("50±0,2±1"), input);
+        assert_eq!(
+            split_range(&input, &NumberFormat::default()),
+            Err(messages::PARSE_WRONG)
+        );
+    }
+
+    #[test]
+    fn test_length_range() {
+        let length = |input: &str| {
+            let field = super::FormElement::InputField("", String::from(input));
+            super::get_lenght(&field, 1., &NumberFormat::default(), &mut false)
+        };
+        assert_eq!(length("1..7"), Ok(4.));
+        assert_eq!(length("..7"), Err(messages::PARSE_WRONG));
+        assert_eq!(length("1.."), Err(messages::PARSE_WRONG));
+        assert_eq!(length("1.7"), Ok(1.7));
+    }
+
+    /// Validates `input` as typed with the given separators and parses it.
+    fn validate_grouped(
+        input: &str,
+        grouping: Grouping,
+        decimal: DecimalSeparator,
+    ) -> Result<f64, &'static str> {
+        let format = NumberFormat {
+            grouping,
+            decimal,
+            ..Default::default()
+        };
+        let mut text = String::from(input);
+        super::validate(&mut text, &format);
+        assert_eq!(text, input);
+        split_numbers(&text, &format)
+    }
+
+    #[test]
+    fn test_validator_grouping() {
+        use DecimalSeparator as D;
+        assert_eq!(
+            validate_grouped("12,345.5", Grouping::Comma, D::Dot),
+            Ok(12345.5)
+        );
+        assert_eq!(
+            validate_grouped("1.234.567", Grouping::Dot, D::Comma),
+            Ok(1234567.)
+        );
+        assert_eq!(
+            validate_grouped("1.234,5", Grouping::Dot, D::Comma),
+            Ok(1234.5)
+        );
+        assert_eq!(
+            validate_grouped("1'234.5", Grouping::Apostrophe, D::Dot),
+            Ok(1234.5)
+        );
+        assert_eq!(
+            validate_grouped("1 234,5", Grouping::Space, D::Comma),
+            Ok(1234.5)
+        );
+    }
+
+    #[test]
+    fn test_validator_grouping_repeats() {
+        let format = |grouping| NumberFormat {
+            grouping,
+            decimal: DecimalSeparator::Comma,
+            ..Default::default()
+        };
+        let mut input = String::from("-4,,");
+        super::validate(&mut input, &format(Grouping::Dot));
+        assert_eq!(input, "-4,,");
+        let mut input = String::from("-4,,");
+        super::validate(&mut input, &format(Grouping::Space));
+        assert_eq!(input, "-4,");
+        let mut input = String::from("1.234.567");
+        super::validate(&mut input, &format(Grouping::None));
+        assert_eq!(input, "1.234567");
+    }
 }
@@ -0,0 +1,64 @@
+//! Surface of threaded parts from the thread pitch and profile.
+//!
+//! The flanks are taken from the basic profile: the surface of a thread is
+//! the surface of a cylinder on the pitch diameter times the length of the
+//! profile over one pitch divided by the pitch.
+
+/// Surface ratio of a thread when the pitch is not known.
+pub const DEFAULT_MULTIPLIER: f64 = 1.5;
+
+#[derive(Copy, Clone, PartialEq, Debug)]
+/// Shape of the thread flanks.
+pub enum ThreadProfile {
+    /// ISO metric, 60° flanks.
+    Metric,
+    /// ISO trapezoidal, 30° flanks.
+    Trapezoidal,
+}
+
+impl ThreadProfile {
+    /// Pitch diameter of an external thread with major diameter `diameter`.
+    pub fn pitch_diameter(&self, diameter: f64, pitch: f64) -> f64 {
+        match self {
+            ThreadProfile::Metric => diameter - 3. * f64::sqrt(3.) / 8. * pitch,
+            ThreadProfile::Trapezoidal => diameter - 0.5 * pitch,
+        }
+    }
+
+    /// Length of the profile over one pitch divided by the pitch.
+    pub fn surface_ratio(&self) -> f64 {
+        match self {
+            // Crest P/8, root P/4 and flanks of 5H/8 at 30°, H = √3/2·P
+            ThreadProfile::Metric => {
+                let flank = 5. / 8. * f64::sqrt(3.) / 2. / 30f64.to_radians().cos();
+                1. / 8. + 1. / 4. + 2. * flank
+            }
+            // Depth P/2 with flanks at 15°, the rest of the pitch is flat
+            ThreadProfile::Trapezoidal => {
+                let angle = 15f64.to_radians();
+                1. - angle.tan() + 1. / angle.cos()
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::ThreadProfile;
+
+    #[test]
+    fn test_metric() {
+        let profile = ThreadProfile::Metric;
+        assert!((profile.surface_ratio() - 1.625).abs() < 1e-12);
+        // M10×1.5: d2 = 9.026 mm
+        assert!((profile.pitch_diameter(10., 1.5) - 9.026).abs() < 1e-3);
+    }
+
+    #[test]
+    fn test_trapezoidal() {
+        let profile = ThreadProfile::Trapezoidal;
+        assert!((profile.surface_ratio() - 1.7673).abs() < 1e-4);
+        // Tr20×4: d2 = 18 mm
+        assert!((profile.pitch_diameter(20., 4.) - 18.).abs() < 1e-12);
+    }
+}